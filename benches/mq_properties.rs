@@ -1,9 +1,18 @@
-use cheetah_string::CheetahString;
+use cheetah_string::{known_strings, CheetahString};
 use compact_str::CompactString;
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
 use smartstring::alias::String as SmartString;
 use std::collections::HashMap;
 
+known_strings! {
+    static PROPERTY_KEYS = [
+        "KEYS", "TAGS", "WAIT", "DELAY", "RETRY_TOPIC", "REAL_TOPIC", "REAL_QID",
+        "TRAN_MSG", "PGROUP", "MIN_OFFSET", "MAX_OFFSET", "BUYER_ID", "TRACE_ON",
+        "INSTANCE_ID", "CORRELATION_ID", "REPLY_TO_CLIENT", "TTL", "UNIQ_KEY",
+        "BORN_HOST", "STORE_HOST",
+    ];
+}
+
 fn properties() -> Vec<(&'static str, &'static str)> {
     vec![
         ("KEYS", "order-10001"),
@@ -102,6 +111,23 @@ fn bench_property_build(c: &mut Criterion) {
         )
     });
 
+    group.bench_function("CheetahString known keys", |b| {
+        b.iter_batched(
+            || (),
+            |_| {
+                let mut map = HashMap::with_capacity(props.len());
+                for (key, value) in black_box(&props) {
+                    map.insert(
+                        black_box(CheetahString::from_known_or_slice(&PROPERTY_KEYS, key)),
+                        black_box(CheetahString::from(*value)),
+                    );
+                }
+                black_box(map)
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
     group.finish();
 }
 
@@ -1,38 +1,49 @@
 use alloc::string::String;
 use core::fmt;
+use core::ops::RangeBounds;
 
+use crate::cheetah_string::staging::Staging;
+use crate::inline::INLINE_CAPACITY;
+use crate::metrics::Constructor;
+use crate::num;
 use crate::CheetahString;
 
 /// Append-heavy builder for constructing Cheetah string values.
 ///
 /// `CheetahBuilder` keeps mutable construction separate from immutable,
 /// clone-cheap [`CheetahString`] values.
-#[derive(Clone, Default)]
+///
+/// Contents live in an inline buffer until they outgrow the 23-byte inline
+/// capacity of [`CheetahString`], so building and finishing a short value
+/// never allocates.
+#[derive(Clone)]
 pub struct CheetahBuilder {
-    inner: String,
+    inner: Staging<INLINE_CAPACITY>,
 }
 
 impl CheetahBuilder {
     /// Creates an empty builder.
     #[inline]
     pub fn new() -> Self {
-        Self {
-            inner: String::new(),
-        }
+        Self::with_capacity(0)
     }
 
     /// Creates an empty builder with at least `capacity` bytes.
+    ///
+    /// Capacities up to the inline limit do not allocate.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            inner: String::with_capacity(capacity),
+            inner: Staging::with_capacity(capacity),
         }
     }
 
     /// Creates a builder from existing owned storage.
     #[inline]
     pub fn from_string(value: String) -> Self {
-        Self { inner: value }
+        Self {
+            inner: Staging::Heap(value),
+        }
     }
 
     /// Appends a string slice.
@@ -47,7 +58,61 @@ impl CheetahBuilder {
         self.inner.push(value);
     }
 
+    /// Appends the decimal representation of `value` without an intermediate
+    /// `String`.
+    #[inline]
+    pub fn push_u64(&mut self, value: u64) {
+        let mut buf = [0; num::MAX_DEC_LEN];
+        self.push_str(num::format_u64(value, &mut buf));
+    }
+
+    /// Appends the decimal representation of `value` without an intermediate
+    /// `String`.
+    #[inline]
+    pub fn push_i64(&mut self, value: i64) {
+        let mut buf = [0; num::MAX_DEC_LEN];
+        self.push_str(num::format_i64(value, &mut buf));
+    }
+
+    /// Appends the decimal representation of `value` without an intermediate
+    /// `String`.
+    #[inline]
+    pub fn push_u32(&mut self, value: u32) {
+        self.push_u64(u64::from(value));
+    }
+
+    /// Appends the decimal representation of `value` without an intermediate
+    /// `String`.
+    #[inline]
+    pub fn push_usize(&mut self, value: usize) {
+        self.push_u64(value as u64);
+    }
+
+    /// Appends `value` in lowercase hexadecimal without a `0x` prefix.
+    #[inline]
+    pub fn push_hex(&mut self, value: u64) {
+        let mut buf = [0; num::MAX_HEX_LEN];
+        self.push_str(num::format_hex(value, &mut buf));
+    }
+
+    /// Appends the shortest decimal representation of `value` that parses
+    /// back to the same `f64`, matching `value.to_string()`.
+    #[inline]
+    pub fn push_f64(&mut self, value: f64) {
+        self.push_display(&value);
+    }
+
+    /// Appends the `Display` output of `value`, formatting straight into the
+    /// builder.
+    #[inline]
+    pub fn push_display<T: fmt::Display + ?Sized>(&mut self, value: &T) {
+        // Writing into the builder never fails.
+        let _ = fmt::Write::write_fmt(self, format_args!("{value}"));
+    }
+
     /// Reserves capacity for at least `additional` more bytes.
+    ///
+    /// The builder stays inline while the reservation fits the inline limit.
     #[inline]
     pub fn reserve(&mut self, additional: usize) {
         self.inner.reserve(additional);
@@ -59,6 +124,224 @@ impl CheetahBuilder {
         self.inner.clear();
     }
 
+    /// Shortens the contents to `new_len` bytes.
+    ///
+    /// Has no effect when `new_len` is at least the current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not on a UTF-8 character boundary. Use
+    /// [`CheetahBuilder::try_truncate`] for a recoverable error.
+    #[inline]
+    pub fn truncate(&mut self, new_len: usize) {
+        self.try_truncate(new_len)
+            .expect("truncate length must be on a UTF-8 character boundary")
+    }
+
+    /// Shortens the contents to `new_len` bytes, or returns a public error
+    /// when `new_len` is not on a character boundary.
+    #[inline]
+    pub fn try_truncate(&mut self, new_len: usize) -> crate::Result<()> {
+        let len = self.len();
+        if new_len >= len {
+            return Ok(());
+        }
+
+        crate::range::check(self.as_str(), new_len, len)?;
+        self.inner.replace_range(new_len, len, "");
+        Ok(())
+    }
+
+    /// Removes and returns the last character.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        let len = self.len();
+        self.inner.replace_range(len - ch.len_utf8(), len, "");
+        Some(ch)
+    }
+
+    /// Inserts a character at byte index `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds or not on a UTF-8 character
+    /// boundary. Use [`CheetahBuilder::try_insert`] for a recoverable error.
+    #[inline]
+    pub fn insert(&mut self, index: usize, ch: char) {
+        self.try_insert(index, ch)
+            .expect("insert index must be in bounds and on a UTF-8 character boundary")
+    }
+
+    /// Inserts a character at byte index `index`, or returns a public error
+    /// when `index` is invalid.
+    #[inline]
+    pub fn try_insert(&mut self, index: usize, ch: char) -> crate::Result<()> {
+        self.try_insert_str(index, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Inserts a string slice at byte index `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds or not on a UTF-8 character
+    /// boundary. Use [`CheetahBuilder::try_insert_str`] for a recoverable
+    /// error.
+    #[inline]
+    pub fn insert_str(&mut self, index: usize, value: &str) {
+        self.try_insert_str(index, value)
+            .expect("insert index must be in bounds and on a UTF-8 character boundary")
+    }
+
+    /// Inserts a string slice at byte index `index`, or returns a public
+    /// error when `index` is invalid.
+    #[inline]
+    pub fn try_insert_str(&mut self, index: usize, value: &str) -> crate::Result<()> {
+        crate::range::check(self.as_str(), index, index)?;
+        self.inner.replace_range(index, index, value);
+        Ok(())
+    }
+
+    /// Removes and returns the character at byte index `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not the start of a character. Use
+    /// [`CheetahBuilder::try_remove`] for a recoverable error.
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> char {
+        self.try_remove(index)
+            .expect("remove index must be in bounds and on a UTF-8 character boundary")
+    }
+
+    /// Removes and returns the character at byte index `index`, or returns a
+    /// public error when `index` is not the start of a character.
+    #[inline]
+    pub fn try_remove(&mut self, index: usize) -> crate::Result<char> {
+        let value = self.as_str();
+        crate::range::check(value, index, index)?;
+        let ch = value[index..]
+            .chars()
+            .next()
+            .ok_or(crate::Error::IndexOutOfBounds {
+                index,
+                len: value.len(),
+            })?;
+        self.inner.replace_range(index, index + ch.len_utf8(), "");
+        Ok(ch)
+    }
+
+    /// Keeps only the characters for which `keep` returns `true`.
+    #[inline]
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, keep: F) {
+        self.inner.retain(keep);
+    }
+
+    /// Removes the given byte range and returns it as a [`CheetahString`].
+    ///
+    /// The range is removed immediately. Removed text that fits inline does
+    /// not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, inverted, or not on valid UTF-8
+    /// character boundaries. Use [`CheetahBuilder::try_drain`] for a
+    /// recoverable error.
+    #[inline]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> CheetahString {
+        self.try_drain(range)
+            .expect("drain range must be in bounds and on UTF-8 character boundaries")
+    }
+
+    /// Removes the given byte range and returns it, or returns a public error
+    /// when the range is invalid.
+    #[inline]
+    pub fn try_drain<R: RangeBounds<usize>>(&mut self, range: R) -> crate::Result<CheetahString> {
+        let (start, end) = self.check_range(&range)?;
+        let drained = CheetahString::from_slice(&self.as_str()[start..end]);
+        self.inner.replace_range(start, end, "");
+        Ok(drained)
+    }
+
+    /// Replaces the given byte range with `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, inverted, or not on valid UTF-8
+    /// character boundaries. Use [`CheetahBuilder::try_replace_range`] for a
+    /// recoverable error.
+    #[inline]
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, value: &str) {
+        self.try_replace_range(range, value)
+            .expect("replace range must be in bounds and on UTF-8 character boundaries")
+    }
+
+    /// Replaces the given byte range with `value`, or returns a public error
+    /// when the range is invalid.
+    #[inline]
+    pub fn try_replace_range<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        value: &str,
+    ) -> crate::Result<()> {
+        let (start, end) = self.check_range(&range)?;
+        self.inner.replace_range(start, end, value);
+        Ok(())
+    }
+
+    /// Splits the builder in two at byte index `at`, returning the tail.
+    ///
+    /// A tail that fits inline does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is out of bounds or not on a UTF-8 character boundary.
+    /// Use [`CheetahBuilder::try_split_off`] for a recoverable error.
+    #[inline]
+    pub fn split_off(&mut self, at: usize) -> CheetahBuilder {
+        self.try_split_off(at)
+            .expect("split index must be in bounds and on a UTF-8 character boundary")
+    }
+
+    /// Splits the builder in two at byte index `at`, or returns a public
+    /// error when `at` is invalid.
+    #[inline]
+    pub fn try_split_off(&mut self, at: usize) -> crate::Result<CheetahBuilder> {
+        crate::range::check(self.as_str(), at, at)?;
+        Ok(Self {
+            inner: self.inner.split_off(at),
+        })
+    }
+
+    /// Appends a copy of the given byte range of the current contents.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, inverted, or not on valid UTF-8
+    /// character boundaries. Use [`CheetahBuilder::try_extend_from_within`]
+    /// for a recoverable error.
+    #[inline]
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, range: R) {
+        self.try_extend_from_within(range)
+            .expect("source range must be in bounds and on UTF-8 character boundaries")
+    }
+
+    /// Appends a copy of the given byte range of the current contents, or
+    /// returns a public error when the range is invalid.
+    #[inline]
+    pub fn try_extend_from_within<R: RangeBounds<usize>>(&mut self, range: R) -> crate::Result<()> {
+        let (start, end) = self.check_range(&range)?;
+        self.inner.extend_from_within(start, end);
+        Ok(())
+    }
+
+    #[inline]
+    fn check_range<R: RangeBounds<usize>>(&self, range: &R) -> crate::Result<(usize, usize)> {
+        let (start, end) = crate::range::resolve(range, self.len())?;
+        crate::range::check(self.as_str(), start, end)?;
+        Ok((start, end))
+    }
+
     /// Returns the current contents.
     #[inline]
     pub fn as_str(&self) -> &str {
@@ -74,22 +357,43 @@ impl CheetahBuilder {
     /// Returns whether the builder is empty.
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.inner.is_empty()
+        self.inner.len() == 0
     }
 
-    /// Returns the allocated capacity in bytes.
+    /// Returns the capacity in bytes; this is the inline limit until the
+    /// builder spills to the heap.
     #[inline]
     pub fn capacity(&self) -> usize {
         self.inner.capacity()
     }
 
+    /// Returns the capacity of the heap buffer, or `None` while inline.
+    #[inline]
+    pub(crate) fn heap_capacity(&self) -> Option<usize> {
+        match &self.inner {
+            Staging::Heap(heap) => Some(heap.capacity()),
+            Staging::Inline(_) => None,
+        }
+    }
+
     /// Freezes this builder into the canonical clone-cheap string value.
     ///
     /// Use [`CheetahBuilder::into_string`] when construction is followed by
     /// more mutation or when spare capacity must be retained.
     #[inline]
     pub fn finish(self) -> CheetahString {
-        CheetahString::from_string(self.inner)
+        self.inner.finish().counted(Constructor::Builder)
+    }
+
+    /// Freezes this builder through `interner`, returning the canonical
+    /// shared value when equal text has been interned before.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn finish_interned(self, interner: &crate::CheetahInterner) -> CheetahString {
+        match self.inner {
+            Staging::Inline(inline) => interner.intern(inline.as_str()),
+            Staging::Heap(heap) => interner.intern_string(heap),
+        }
     }
 
     /// Freezes the builder into the canonical clone-cheap string value.
@@ -103,9 +407,34 @@ impl CheetahBuilder {
     }
 
     /// Returns the owned `String` backing this builder.
+    ///
+    /// A builder that is still inline allocates a `String` for its contents.
     #[inline]
     pub fn into_string(self) -> String {
-        self.inner
+        self.inner.into_string()
+    }
+
+    /// Freezes the builder into `bytes::Bytes` without copying its contents
+    /// once it has spilled to the heap.
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub fn finish_bytes(self) -> bytes::Bytes {
+        bytes::Bytes::from(self.inner.into_string())
+    }
+
+    /// Converts the builder into `bytes::BytesMut` without copying its
+    /// contents, so a network writer can keep appending.
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub fn finish_bytes_mut(self) -> bytes::BytesMut {
+        bytes::BytesMut::from(self.finish_bytes())
+    }
+}
+
+impl Default for CheetahBuilder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -128,7 +457,11 @@ impl From<&str> for CheetahBuilder {
 impl Extend<char> for CheetahBuilder {
     #[inline]
     fn extend<T: IntoIterator<Item = char>>(&mut self, iter: T) {
-        self.inner.extend(iter);
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for ch in iter {
+            self.push(ch);
+        }
     }
 }
 
@@ -141,11 +474,25 @@ impl<'a> Extend<&'a str> for CheetahBuilder {
     }
 }
 
+impl fmt::Write for CheetahBuilder {
+    #[inline]
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.push_str(value);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, value: char) -> fmt::Result {
+        self.push(value);
+        Ok(())
+    }
+}
+
 impl fmt::Debug for CheetahBuilder {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("CheetahBuilder")
-            .field("value", &self.inner)
+            .field("value", &self.inner.as_str())
             .field("capacity", &self.inner.capacity())
             .finish()
     }
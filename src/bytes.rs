@@ -120,6 +120,26 @@ impl CheetahBytes {
         CheetahString::try_copy_from_bytes(self.inner).map_err(|error| error.into_parts().1)
     }
 
+    /// Validates UTF-8 and converts this buffer into a `CheetahString`
+    /// without copying its payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns the original buffer together with the validation error if the
+    /// bytes are not valid UTF-8.
+    #[inline]
+    pub fn try_into_cheetah_string(self) -> Result<CheetahString, FromUtf8BytesError> {
+        CheetahString::try_from_shared_bytes(self.inner)
+    }
+
+    /// Validates UTF-8 and returns a `CheetahString` that shares this buffer.
+    ///
+    /// The `CheetahBytes` value remains available regardless of success.
+    #[inline]
+    pub fn try_to_cheetah_string(&self) -> Result<CheetahString, Utf8Error> {
+        CheetahString::try_from_shared_bytes(self.inner.clone()).map_err(|error| error.utf8_error())
+    }
+
     /// Validates UTF-8 and copies this buffer into a `CheetahString`.
     ///
     /// The `CheetahBytes` value remains available regardless of success.
@@ -128,7 +148,8 @@ impl CheetahBytes {
         CheetahString::try_from_bytes(self.as_bytes())
     }
 
-    /// Converts bytes into `CheetahString` without validating UTF-8.
+    /// Converts bytes into `CheetahString` without validating UTF-8 or
+    /// copying the payload.
     ///
     /// # Safety
     ///
@@ -197,7 +218,7 @@ impl TryFrom<CheetahBytes> for CheetahString {
 
     #[inline]
     fn try_from(bytes: CheetahBytes) -> Result<Self, Self::Error> {
-        CheetahString::try_copy_from_bytes(bytes.into_bytes())
+        bytes.try_into_cheetah_string()
     }
 }
 
@@ -206,7 +227,7 @@ impl TryFrom<&CheetahBytes> for CheetahString {
 
     #[inline]
     fn try_from(bytes: &CheetahBytes) -> Result<Self, Self::Error> {
-        bytes.try_copy_to_cheetah_string()
+        bytes.try_to_cheetah_string()
     }
 }
 
@@ -0,0 +1,426 @@
+//! Case-insensitive wrappers for map keys and comparisons.
+
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use crate::heap_size::{HeapSize, MemoryTally};
+use crate::CheetahString;
+
+/// A case folding used by [`CaseInsensitive`] and [`CaseInsensitiveStr`].
+///
+/// Implementations must be consistent: texts that compare equal with
+/// [`CaseFolding::fold_eq`] must order as [`Ordering::Equal`] and feed the
+/// same data to the hasher.
+pub trait CaseFolding {
+    /// Returns whether `a` and `b` are equal after folding.
+    fn fold_eq(a: &str, b: &str) -> bool;
+
+    /// Orders `a` and `b` by their folded text.
+    fn fold_cmp(a: &str, b: &str) -> Ordering;
+
+    /// Feeds the folded form of `text` to `state`.
+    fn fold_hash<H: Hasher>(text: &str, state: &mut H);
+}
+
+/// Folds ASCII letters only; other characters must match exactly.
+///
+/// This is the folding for protocol text such as HTTP header names.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AsciiFolding;
+
+impl CaseFolding for AsciiFolding {
+    #[inline]
+    fn fold_eq(a: &str, b: &str) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+
+    #[inline]
+    fn fold_cmp(a: &str, b: &str) -> Ordering {
+        let fold = |byte: &u8| byte.to_ascii_lowercase();
+        a.as_bytes()
+            .iter()
+            .map(fold)
+            .cmp(b.as_bytes().iter().map(fold))
+    }
+
+    fn fold_hash<H: Hasher>(text: &str, state: &mut H) {
+        let mut buf = [0u8; HASH_CHUNK];
+        for chunk in text.as_bytes().chunks(HASH_CHUNK) {
+            let folded = &mut buf[..chunk.len()];
+            folded.copy_from_slice(chunk);
+            folded.make_ascii_lowercase();
+            state.write(folded);
+        }
+        state.write_u8(0xff);
+    }
+}
+
+/// Applies Unicode simple case folding, so `"STRAẞE"` matches `"straße"`
+/// and `"ΣΊΣΥΦΟΣ"` matches `"σίσυφος"`.
+///
+/// Characters are folded one at a time through the standard library's
+/// one-to-one case mappings, which covers the common and simple mappings of
+/// the Unicode `CaseFolding.txt` table. Full foldings that change the length,
+/// such as `ß` to `ss`, and the Turkic dotted and dotless `i` mappings are
+/// not applied.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UnicodeFolding;
+
+impl UnicodeFolding {
+    #[inline]
+    fn fold(c: char) -> char {
+        if c.is_ascii() {
+            return c.to_ascii_lowercase();
+        }
+        // Dotless `i` only folds to `i` under the Turkic mappings.
+        if c == '\u{131}' {
+            return c;
+        }
+
+        let mut upper = c.to_uppercase();
+        let upper = match (upper.next(), upper.next()) {
+            (Some(upper), None) => upper,
+            _ => c,
+        };
+        let mut lower = upper.to_lowercase();
+        match (lower.next(), lower.next()) {
+            (Some(lower), None) => lower,
+            _ => upper,
+        }
+    }
+}
+
+impl CaseFolding for UnicodeFolding {
+    #[inline]
+    fn fold_eq(a: &str, b: &str) -> bool {
+        a == b || a.chars().map(Self::fold).eq(b.chars().map(Self::fold))
+    }
+
+    #[inline]
+    fn fold_cmp(a: &str, b: &str) -> Ordering {
+        a.chars().map(Self::fold).cmp(b.chars().map(Self::fold))
+    }
+
+    fn fold_hash<H: Hasher>(text: &str, state: &mut H) {
+        let mut buf = [0u8; HASH_CHUNK];
+        let mut len = 0;
+        for c in text.chars().map(Self::fold) {
+            if len + c.len_utf8() > HASH_CHUNK {
+                state.write(&buf[..len]);
+                len = 0;
+            }
+            len += c.encode_utf8(&mut buf[len..]).len();
+        }
+        state.write(&buf[..len]);
+        state.write_u8(0xff);
+    }
+}
+
+/// Folded text is hashed in chunks of this many bytes.
+const HASH_CHUNK: usize = 64;
+
+/// A string whose `Eq`, `Ord` and `Hash` ignore case.
+///
+/// The wrapped value keeps its original spelling for display, debugging and
+/// serde; only comparisons go through the folding `F`, which defaults to
+/// [`AsciiFolding`]. Maps keyed by `CaseInsensitive` can be queried with a
+/// plain `&str` through [`CaseInsensitiveStr::new`] without allocating.
+///
+/// Use [`CaseInsensitive::unicode`] and [`CaseInsensitiveStr::unicode`] for
+/// [`UnicodeFolding`].
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{CaseInsensitive, CaseInsensitiveStr, CheetahString};
+/// use std::collections::HashMap;
+///
+/// let mut headers = HashMap::new();
+/// headers.insert(CaseInsensitive::new(CheetahString::from("Content-Type")), "text/plain");
+///
+/// assert_eq!(headers.get(CaseInsensitiveStr::new("content-type")), Some(&"text/plain"));
+/// let (name, _) = headers.iter().next().unwrap();
+/// assert_eq!(name.to_string(), "Content-Type");
+/// ```
+pub struct CaseInsensitive<S = CheetahString, F = AsciiFolding> {
+    value: S,
+    folding: PhantomData<fn() -> F>,
+}
+
+impl<S> CaseInsensitive<S> {
+    /// Wraps `value`, comparing it with [`AsciiFolding`].
+    #[inline]
+    pub const fn new(value: S) -> Self {
+        Self::with_folding(value)
+    }
+}
+
+impl<S> CaseInsensitive<S, UnicodeFolding> {
+    /// Wraps `value`, comparing it with [`UnicodeFolding`].
+    #[inline]
+    pub const fn unicode(value: S) -> Self {
+        Self::with_folding(value)
+    }
+}
+
+impl<S, F> CaseInsensitive<S, F> {
+    /// Wraps `value`, comparing it with the folding `F`.
+    #[inline]
+    pub const fn with_folding(value: S) -> Self {
+        Self {
+            value,
+            folding: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &S {
+        &self.value
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.value
+    }
+}
+
+impl<S: AsRef<str>, F> CaseInsensitive<S, F> {
+    /// Returns the text in its original spelling.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.value.as_ref()
+    }
+
+    /// Returns the text as a borrowed case-insensitive string.
+    #[inline]
+    pub fn as_case_insensitive_str(&self) -> &CaseInsensitiveStr<F> {
+        CaseInsensitiveStr::with_folding(self.as_str())
+    }
+}
+
+impl<S: Clone, F> Clone for CaseInsensitive<S, F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::with_folding(self.value.clone())
+    }
+}
+
+impl<S: Copy, F> Copy for CaseInsensitive<S, F> {}
+
+impl<S: Default, F> Default for CaseInsensitive<S, F> {
+    #[inline]
+    fn default() -> Self {
+        Self::with_folding(S::default())
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFolding> PartialEq for CaseInsensitive<S, F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        F::fold_eq(self.as_str(), other.as_str())
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFolding> Eq for CaseInsensitive<S, F> {}
+
+impl<S: AsRef<str>, F: CaseFolding> PartialEq<str> for CaseInsensitive<S, F> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        F::fold_eq(self.as_str(), other)
+    }
+}
+
+impl<'a, S: AsRef<str>, F: CaseFolding> PartialEq<&'a str> for CaseInsensitive<S, F> {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        F::fold_eq(self.as_str(), other)
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFolding> PartialOrd for CaseInsensitive<S, F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFolding> Ord for CaseInsensitive<S, F> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        F::fold_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFolding> Hash for CaseInsensitive<S, F> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        F::fold_hash(self.as_str(), state);
+    }
+}
+
+impl<S, F> Deref for CaseInsensitive<S, F> {
+    type Target = S;
+
+    #[inline]
+    fn deref(&self) -> &S {
+        &self.value
+    }
+}
+
+impl<S: AsRef<str>, F> AsRef<str> for CaseInsensitive<S, F> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFolding> Borrow<CaseInsensitiveStr<F>> for CaseInsensitive<S, F> {
+    #[inline]
+    fn borrow(&self) -> &CaseInsensitiveStr<F> {
+        self.as_case_insensitive_str()
+    }
+}
+
+impl<S: fmt::Display, F> fmt::Display for CaseInsensitive<S, F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for CaseInsensitive<S, F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl<S, F> From<S> for CaseInsensitive<S, F> {
+    #[inline]
+    fn from(value: S) -> Self {
+        Self::with_folding(value)
+    }
+}
+
+impl<F> From<&str> for CaseInsensitive<CheetahString, F> {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::with_folding(CheetahString::from_slice(value))
+    }
+}
+
+impl<F> From<String> for CaseInsensitive<CheetahString, F> {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::with_folding(CheetahString::from_string(value))
+    }
+}
+
+impl<S: HeapSize, F> HeapSize for CaseInsensitive<S, F> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        self.value.tally_heap(tally);
+    }
+}
+
+/// A borrowed `str` whose `Eq`, `Ord` and `Hash` ignore case.
+///
+/// This is the borrowed form of [`CaseInsensitive`], used to look up
+/// case-insensitive map keys from plain text. Both use the folding `F`, so
+/// they hash and compare alike.
+#[repr(transparent)]
+pub struct CaseInsensitiveStr<F = AsciiFolding> {
+    folding: PhantomData<fn() -> F>,
+    text: str,
+}
+
+impl CaseInsensitiveStr {
+    /// Views `text` as a string compared with [`AsciiFolding`].
+    #[inline]
+    pub fn new(text: &str) -> &Self {
+        Self::with_folding(text)
+    }
+}
+
+impl CaseInsensitiveStr<UnicodeFolding> {
+    /// Views `text` as a string compared with [`UnicodeFolding`].
+    #[inline]
+    pub fn unicode(text: &str) -> &Self {
+        Self::with_folding(text)
+    }
+}
+
+impl<F> CaseInsensitiveStr<F> {
+    /// Views `text` as a string compared with the folding `F`.
+    #[inline]
+    pub fn with_folding(text: &str) -> &Self {
+        // SAFETY: `Self` is `repr(transparent)` over `str`; the only other
+        // field is a zero-sized marker. The cast keeps the length metadata
+        // and the lifetime of `text`.
+        unsafe { &*(text as *const str as *const Self) }
+    }
+
+    /// Returns the text in its original spelling.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+}
+
+impl<F: CaseFolding> PartialEq for CaseInsensitiveStr<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        F::fold_eq(self.as_str(), other.as_str())
+    }
+}
+
+impl<F: CaseFolding> Eq for CaseInsensitiveStr<F> {}
+
+impl<F: CaseFolding> PartialOrd for CaseInsensitiveStr<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: CaseFolding> Ord for CaseInsensitiveStr<F> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        F::fold_cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl<F: CaseFolding> Hash for CaseInsensitiveStr<F> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        F::fold_hash(self.as_str(), state);
+    }
+}
+
+impl<F> AsRef<str> for CaseInsensitiveStr<F> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<F> fmt::Display for CaseInsensitiveStr<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<F> fmt::Debug for CaseInsensitiveStr<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
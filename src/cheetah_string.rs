@@ -1,19 +1,82 @@
 mod construct;
 mod convert;
+mod format;
+mod join;
+mod number;
 mod pattern;
 mod query;
 mod repr;
+mod shared;
+mod split;
+pub(crate) mod staging;
+mod storage;
 mod traits;
 
+pub use join::JoinCheetah;
 pub use pattern::{SplitPattern, SplitStr, StrPattern};
-use repr::InnerString;
+use repr::{InnerString, INLINE_CAPACITY};
+pub use split::{
+    MatchIndices, Matches, OwnedPieces, RMatchIndices, RMatches, RSplit, RSplitN, RSplitTerminator,
+    Split, SplitInclusive, SplitN, SplitTerminator,
+};
+pub use storage::StorageKind;
 
 /// Immutable string value with inline, static, or shared backing.
 ///
 /// All clones are allocation-free. Use `CheetahBuilder` or `String` while
 /// contents are still being mutated.
+///
+/// This is [`CheetahStringN`] with the default 23-byte inline capacity, which
+/// keeps the value at 24 bytes.
+pub type CheetahString = CheetahStringN<INLINE_CAPACITY>;
+
+/// [`CheetahString`] with an inline capacity of `N` bytes.
+///
+/// Values of up to `N` bytes are stored inline; longer values use the same
+/// static and shared storage as [`CheetahString`]. Pick a larger `N` when keys
+/// cluster just above 23 bytes, at the cost of a larger value. `N` must be at
+/// most 63.
+///
+/// The value takes `N + 1` bytes rounded up to pointer alignment, but never
+/// less than the static and sliced variants need: 24 bytes on 64-bit targets
+/// and 16 bytes on 32-bit targets. A capacity below 23 therefore holds less
+/// text inline without making the value smaller than [`CheetahString`].
+///
+/// Every capacity has the same API, traits, and conversions. Values of
+/// different capacities compare equal when their text is equal and convert
+/// with [`CheetahStringN::into_capacity`], which shares long backings instead
+/// of copying them.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{CheetahString, CheetahString47};
+///
+/// // A 28-byte key stays inline instead of allocating.
+/// let key = CheetahString47::from("7F00000100002A9F000000000001");
+///
+/// let canonical: CheetahString = key.clone().into_capacity();
+/// assert_eq!(canonical, key);
+/// ```
 #[derive(Clone)]
 #[repr(transparent)]
-pub struct CheetahString {
-    inner: InnerString,
+pub struct CheetahStringN<const N: usize> {
+    inner: InnerString<N>,
 }
+
+/// [`CheetahStringN`] with a 15-byte inline capacity.
+///
+/// This only limits how much text is stored inline: the value is still 24
+/// bytes on 64-bit targets, the same as [`CheetahString`], because static and
+/// sliced values need that much. It shrinks to 16 bytes on 32-bit targets.
+/// Use it when text longer than 15 bytes should be shared rather than copied
+/// on every clone.
+pub type CheetahString15 = CheetahStringN<15>;
+
+/// [`CheetahStringN`] with a 31-byte inline capacity (32-byte value on 64-bit
+/// targets).
+pub type CheetahString31 = CheetahStringN<31>;
+
+/// [`CheetahStringN`] with a 47-byte inline capacity (48-byte value on 64-bit
+/// targets).
+pub type CheetahString47 = CheetahStringN<47>;
@@ -1,32 +1,75 @@
 use alloc::string::String;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::fmt;
 use core::str::{self, Utf8Error};
 
-use super::repr::{InnerString, INLINE_CAPACITY};
-use super::CheetahString;
+use super::repr::{InnerString, SharedSlice};
+use super::shared::SharedStr;
+use super::staging::Staging;
+use super::CheetahStringN;
 use crate::inline::InlineStr;
+use crate::metrics::Constructor;
+#[cfg(doc)]
+use crate::CheetahString;
+use crate::KnownStrings;
 
-impl CheetahString {
+impl<const N: usize> CheetahStringN<N> {
     #[inline]
     pub const fn empty() -> Self {
-        CheetahString {
+        Self {
             inner: InnerString::Inline(InlineStr::empty()),
         }
     }
 
     #[inline]
     pub fn new() -> Self {
-        CheetahString::default()
+        Self::default()
     }
 
     #[inline]
     pub const fn from_static_str(s: &'static str) -> Self {
-        CheetahString {
+        Self {
             inner: InnerString::Static(s),
         }
     }
 
+    /// Creates an inline value in a const context.
+    ///
+    /// Unlike [`CheetahString::from_static_str`], the text is copied into
+    /// the value itself, so reading it never follows a pointer. Prefer the
+    /// [`crate::cheetah!`] macro, which guarantees compile-time evaluation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is longer than the inline capacity `N`. In a `const` or
+    /// `static` item this is a compile error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// const TOPIC: CheetahString = CheetahString::inline_from_str("orders");
+    /// assert_eq!(TOPIC.as_str(), "orders");
+    /// ```
+    #[inline]
+    pub const fn inline_from_str(s: &str) -> Self {
+        Self::__inline_from_parts(&[s])
+    }
+
+    /// Concatenates `parts` into an inline value in a const context.
+    ///
+    /// Support function for [`crate::cheetah_const!`]; not part of the
+    /// public API.
+    #[doc(hidden)]
+    #[inline]
+    pub const fn __inline_from_parts(parts: &[&str]) -> Self {
+        Self {
+            inner: InnerString::Inline(InlineStr::from_parts_const(parts)),
+        }
+    }
+
     /// Creates a `CheetahString` from a byte vector without validating UTF-8.
     ///
     /// # Safety
@@ -35,22 +78,22 @@ impl CheetahString {
     /// lifetime of the returned `CheetahString`.
     #[inline]
     pub unsafe fn from_utf8_unchecked_vec(s: Vec<u8>) -> Self {
-        CheetahString::from_validated_vec_unchecked(s)
+        Self::from_validated_vec_unchecked(s).counted(Constructor::FromVec)
     }
 
     #[inline]
     fn from_validated_vec_unchecked(s: Vec<u8>) -> Self {
-        if s.len() <= INLINE_CAPACITY {
+        if s.len() <= N {
             // SAFETY: Callers validate UTF-8 before reaching this helper.
             let value = unsafe { str::from_utf8_unchecked(&s) };
             let inline = InlineStr::from_str(value).expect("short str must fit inline storage");
-            return CheetahString {
+            return Self {
                 inner: InnerString::Inline(inline),
             };
         }
 
         // SAFETY: Callers validate UTF-8 before reaching this helper.
-        CheetahString::from_string(unsafe { String::from_utf8_unchecked(s) })
+        Self::freeze_string(unsafe { String::from_utf8_unchecked(s) })
     }
 
     /// Creates a `CheetahString` from a byte vector with UTF-8 validation.
@@ -73,7 +116,7 @@ impl CheetahString {
     /// ```
     pub fn try_from_vec(v: Vec<u8>) -> Result<Self, Utf8Error> {
         str::from_utf8(&v)?;
-        Ok(CheetahString::from_validated_vec_unchecked(v))
+        Ok(Self::from_validated_vec_unchecked(v).counted(Constructor::FromVec))
     }
 
     /// Creates a `CheetahString` from a byte slice with UTF-8 validation.
@@ -96,7 +139,7 @@ impl CheetahString {
     /// ```
     pub fn try_from_bytes(b: &[u8]) -> Result<Self, Utf8Error> {
         let s = str::from_utf8(b)?;
-        Ok(CheetahString::from_slice(s))
+        Ok(Self::copy_str(s).counted(Constructor::FromBytes))
     }
 
     /// Creates a `CheetahString` from a byte slice without validating UTF-8.
@@ -107,7 +150,7 @@ impl CheetahString {
     #[inline]
     pub unsafe fn from_utf8_unchecked_bytes(b: &[u8]) -> Self {
         // SAFETY: The caller guarantees that `b` contains valid UTF-8.
-        CheetahString::from_slice(unsafe { str::from_utf8_unchecked(b) })
+        Self::copy_str(unsafe { str::from_utf8_unchecked(b) }).counted(Constructor::FromBytes)
     }
 
     /// Creates a `CheetahString` from a shared byte vector with UTF-8 validation.
@@ -118,10 +161,10 @@ impl CheetahString {
     #[inline]
     pub fn try_from_arc_vec(s: Arc<Vec<u8>>) -> Result<Self, Utf8Error> {
         match Arc::try_unwrap(s) {
-            Ok(v) => CheetahString::try_from_vec(v),
+            Ok(v) => Self::try_from_vec(v),
             Err(s) => {
                 let s = str::from_utf8(s.as_slice())?;
-                Ok(CheetahString::from_slice(s))
+                Ok(Self::copy_str(s).counted(Constructor::FromVec))
             }
         }
     }
@@ -133,38 +176,118 @@ impl CheetahString {
     /// The caller must guarantee that `s` contains valid UTF-8.
     #[inline]
     pub unsafe fn from_utf8_unchecked_arc_vec(s: Arc<Vec<u8>>) -> Self {
-        CheetahString::from_validated_arc_vec_unchecked(s)
+        Self::from_validated_arc_vec_unchecked(s).counted(Constructor::FromVec)
     }
 
     #[inline]
     fn from_validated_arc_vec_unchecked(s: Arc<Vec<u8>>) -> Self {
         match Arc::try_unwrap(s) {
-            Ok(v) => CheetahString::from_validated_vec_unchecked(v),
+            Ok(v) => Self::from_validated_vec_unchecked(v),
             Err(s) => {
                 // SAFETY: Callers validate UTF-8 before reaching this helper.
-                unsafe { CheetahString::from_utf8_unchecked_bytes(s.as_slice()) }
+                Self::copy_str(unsafe { str::from_utf8_unchecked(s.as_slice()) })
             }
         }
     }
 
+    /// Concatenates `pieces` inline when the result fits, otherwise into a
+    /// single right-sized shared allocation.
+    ///
+    /// The iterator is cloned to measure the total length first.
+    pub(super) fn from_pieces<'a, I>(pieces: I) -> Self
+    where
+        I: Iterator<Item = &'a str> + Clone,
+    {
+        let len = pieces
+            .clone()
+            .try_fold(0usize, |len, piece| len.checked_add(piece.len()))
+            .expect("capacity overflow");
+
+        if len <= N {
+            let mut inline = InlineStr::empty();
+            if pieces.clone().all(|piece| inline.try_push_str(piece)) {
+                return Self {
+                    inner: InnerString::Inline(inline),
+                };
+            }
+        } else if let Some(shared) = SharedStr::try_fill(len, |writer| {
+            pieces
+                .clone()
+                .try_for_each(|piece| fmt::Write::write_str(writer, piece))
+        }) {
+            return Self {
+                inner: InnerString::Shared(shared),
+            };
+        }
+
+        // The pieces changed between passes; copy them into a growable
+        // buffer instead.
+        let mut out = Staging::with_capacity(len);
+        pieces.for_each(|piece| out.push_str(piece));
+        out.finish()
+    }
+
+    /// Concatenates `parts` inline when the result fits, otherwise into a
+    /// single shared allocation.
+    #[inline]
+    pub(super) fn from_parts(parts: &[&str]) -> Self {
+        Self::from_pieces(parts.iter().copied())
+    }
+
     #[inline]
     pub fn from_slice(s: &str) -> Self {
+        Self::copy_str(s).counted(Constructor::FromSlice)
+    }
+
+    /// Copies `s` inline or into a new shared block.
+    #[inline]
+    pub(super) fn copy_str(s: &str) -> Self {
         if let Some(inline) = InlineStr::from_str(s) {
-            CheetahString {
+            Self {
                 inner: InnerString::Inline(inline),
             }
         } else {
-            // Use Arc<str> for long borrowed strings to avoid the extra String header.
-            let arc_str: Arc<str> = Arc::from(s);
-            CheetahString {
-                inner: InnerString::Shared(arc_str),
+            Self {
+                inner: InnerString::Shared(SharedStr::from_str(s)),
             }
         }
     }
 
+    /// Returns the registered `'static` value equal to `s`, or copies `s`
+    /// like [`CheetahString::from_slice`] when `known` does not contain it.
+    ///
+    /// Matches never allocate and always point at the declared text, so
+    /// decoded copies of a well-known key share one address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::{known_strings, CheetahString};
+    ///
+    /// known_strings! {
+    ///     static KEYS = ["KEYS", "TAGS", "WAIT"];
+    /// }
+    ///
+    /// let frame = String::from("TAGS");
+    /// let a = CheetahString::from_known_or_slice(&KEYS, &frame);
+    /// let b = CheetahString::from_known_or_slice(&KEYS, "TAGS");
+    /// assert_eq!(a.as_ptr(), b.as_ptr());
+    ///
+    /// let other = CheetahString::from_known_or_slice(&KEYS, "UNKNOWN");
+    /// assert_eq!(other, "UNKNOWN");
+    /// ```
+    #[inline]
+    pub fn from_known_or_slice(known: &KnownStrings, s: &str) -> Self {
+        let value = match known.get(s) {
+            Some(word) => Self::from_static_str(word),
+            None => Self::copy_str(s),
+        };
+        value.counted(Constructor::Known)
+    }
+
     #[inline]
     pub fn from_string(s: String) -> Self {
-        CheetahString::from_string_shared(s)
+        Self::freeze_string(s).counted(Constructor::FromString)
     }
 
     /// Freezes an owned string into the canonical immutable representation.
@@ -186,32 +309,72 @@ impl CheetahString {
     /// source compatibility.
     #[inline]
     pub fn from_string_shared(s: String) -> Self {
+        Self::freeze_string(s).counted(Constructor::FromString)
+    }
+
+    /// Freezes `s` inline or into a new shared block.
+    #[inline]
+    pub(super) fn freeze_string(s: String) -> Self {
         if let Some(inline) = InlineStr::from_str(&s) {
-            CheetahString {
+            Self {
                 inner: InnerString::Inline(inline),
             }
         } else {
-            // Use Arc<str> for long strings to avoid double allocation
-            let arc_str: Arc<str> = s.into_boxed_str().into();
-            CheetahString {
-                inner: InnerString::Shared(arc_str),
+            Self {
+                inner: InnerString::Shared(SharedStr::from_str(&s)),
             }
         }
     }
 
     #[inline]
     pub fn from_arc_string(s: Arc<String>) -> Self {
-        match Arc::try_unwrap(s) {
-            Ok(s) => CheetahString::from_string(s),
-            Err(s) => CheetahString::from_slice(s.as_str()),
+        let value = match Arc::try_unwrap(s) {
+            Ok(s) => Self::freeze_string(s),
+            Err(s) => Self::copy_str(s.as_str()),
+        };
+        value.counted(Constructor::FromString)
+    }
+
+    /// Validates UTF-8 and wraps a `bytes::Bytes` buffer without copying its
+    /// payload.
+    ///
+    /// Long values keep `b` (or the slice of a larger frame it refers to)
+    /// alive and share it across clones. Values that fit inline are copied
+    /// so the buffer can be released immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns the original buffer together with the validation error if the
+    /// bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bytes::Bytes;
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let frame = Bytes::from("topic=".to_string() + &"orders-".repeat(8));
+    /// let value = CheetahString::try_from_shared_bytes(frame.slice(6..)).unwrap();
+    /// assert_eq!(value.as_ptr(), frame[6..].as_ptr());
+    /// ```
+    #[inline]
+    #[cfg(feature = "bytes")]
+    pub fn try_from_shared_bytes(b: bytes::Bytes) -> Result<Self, crate::FromUtf8BytesError> {
+        match str::from_utf8(b.as_ref()) {
+            Ok(_) => Ok(Self::from_validated_bytes_unchecked(b).counted(Constructor::FromBytes)),
+            Err(error) => Err(crate::FromUtf8BytesError::new(b, error)),
         }
     }
 
+    /// Validates UTF-8 and copies a `bytes::Bytes` buffer into a new value.
+    ///
+    /// Use [`CheetahString::try_from_shared_bytes`] to keep the buffer
+    /// instead of copying it.
     #[inline]
     #[cfg(feature = "bytes")]
     pub fn try_copy_from_bytes(b: bytes::Bytes) -> Result<Self, crate::FromUtf8BytesError> {
         match str::from_utf8(b.as_ref()) {
-            Ok(value) => Ok(CheetahString::from_slice(value)),
+            Ok(value) => Ok(Self::copy_str(value).counted(Constructor::FromBytes)),
             Err(error) => Err(crate::FromUtf8BytesError::new(b, error)),
         }
     }
@@ -223,67 +386,222 @@ impl CheetahString {
     #[inline]
     #[cfg(feature = "bytes")]
     pub fn try_from_bytes_buf(b: bytes::Bytes) -> Result<Self, Utf8Error> {
-        CheetahString::try_copy_from_bytes(b).map_err(|error| error.into_parts().1)
+        Self::try_copy_from_bytes(b).map_err(|error| error.into_parts().1)
     }
 
     /// Creates a `CheetahString` from `bytes::Bytes` without validating UTF-8.
     ///
+    /// Like [`CheetahString::try_from_shared_bytes`], long values share the
+    /// buffer instead of copying it.
+    ///
     /// # Safety
     ///
     /// The caller must guarantee that `b` contains valid UTF-8.
     #[inline]
     #[cfg(feature = "bytes")]
     pub unsafe fn from_utf8_unchecked_bytes_buf(b: bytes::Bytes) -> Self {
-        CheetahString::from_validated_bytes_unchecked(b)
+        Self::from_validated_bytes_unchecked(b).counted(Constructor::FromBytes)
     }
 
     #[inline]
     #[cfg(feature = "bytes")]
     fn from_validated_bytes_unchecked(b: bytes::Bytes) -> Self {
-        // SAFETY: Callers validate UTF-8 before reaching this helper.
-        unsafe { CheetahString::from_utf8_unchecked_bytes(b.as_ref()) }
+        if b.len() <= N {
+            // SAFETY: Callers validate UTF-8 before reaching this helper.
+            return Self::copy_str(unsafe { str::from_utf8_unchecked(b.as_ref()) });
+        }
+
+        Self {
+            // SAFETY: Callers validate UTF-8 before reaching this helper.
+            inner: InnerString::Shared(unsafe { SharedStr::from_utf8_bytes_unchecked(b) }),
+        }
     }
 
     #[inline]
-    pub fn as_str(&self) -> &str {
+    pub const fn as_str(&self) -> &str {
         match &self.inner {
             InnerString::Inline(inline) => inline.as_str(),
             InnerString::Static(s) => s,
-            InnerString::Shared(s) => s.as_ref(),
+            InnerString::Shared(s) => s.as_str(),
+            InnerString::Sliced(s) => s.as_str(),
         }
     }
 
     #[inline]
-    pub fn as_bytes(&self) -> &[u8] {
-        match &self.inner {
-            InnerString::Inline(inline) => inline.as_bytes(),
-            InnerString::Static(s) => s.as_bytes(),
-            InnerString::Shared(s) => s.as_bytes(),
-        }
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
     }
 
     #[inline]
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         match &self.inner {
             InnerString::Inline(inline) => inline.len(),
             InnerString::Static(s) => s.len(),
             InnerString::Shared(s) => s.len(),
+            InnerString::Sliced(s) => s.len(),
         }
     }
 
     #[inline]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         match &self.inner {
             InnerString::Inline(inline) => inline.is_empty(),
             InnerString::Static(s) => s.is_empty(),
-            InnerString::Shared(s) => s.is_empty(),
+            InnerString::Shared(s) => s.len() == 0,
+            InnerString::Sliced(s) => s.len() == 0,
         }
     }
+
+    /// Converts a sub-slice borrowed from `self.as_str()` into an owned value
+    /// through [`slice_unchecked`](Self::slice_unchecked).
+    #[inline]
+    pub(super) fn owned_piece(&self, piece: &str) -> Self {
+        let base = self.as_str().as_ptr() as usize;
+        let start = (piece.as_ptr() as usize).wrapping_sub(base);
+        debug_assert!(start <= self.len() && piece.len() <= self.len() - start);
+
+        self.slice_unchecked(start, start + piece.len())
+    }
+
+    /// Returns `self[start..end]` without copying long text.
+    ///
+    /// Static values yield static sub-slices of any length. Otherwise short
+    /// results are stored inline and shared values yield a window that keeps
+    /// the parent allocation alive. The caller must have validated that the
+    /// range is in bounds and on UTF-8 character boundaries.
+    #[inline]
+    pub(super) fn slice_unchecked(&self, start: usize, end: usize) -> Self {
+        debug_assert!(start <= end && end <= self.len());
+
+        if start == 0 && end == self.len() {
+            return self.clone();
+        }
+        if let InnerString::Static(s) = self.inner {
+            return Self::from_static_str(&s[start..end]);
+        }
+
+        let value = &self.as_str()[start..end];
+        if let Some(inline) = InlineStr::from_str(value) {
+            return Self {
+                inner: InnerString::Inline(inline),
+            };
+        }
+
+        let window = match &self.inner {
+            InnerString::Inline(_) | InnerString::Static(_) => None,
+            InnerString::Shared(backing) => SharedSlice::new(backing.clone(), start, end),
+            InnerString::Sliced(slice) => {
+                let offset = slice.start();
+                SharedSlice::new(slice.backing().clone(), offset + start, offset + end)
+            }
+        };
+
+        match window {
+            Some(window) => Self {
+                inner: InnerString::Sliced(window),
+            },
+            None => Self::copy_str(value),
+        }
+    }
+
+    /// Returns an equal value that does not keep a larger parent allocation
+    /// alive.
+    ///
+    /// Substrings of long shared values borrow their parent's backing, and
+    /// values adopted from `bytes::Bytes` keep the whole frame they were
+    /// sliced from alive. Call `detach` before storing such a view long-term
+    /// so the parent buffer can be released; both are copied into storage of
+    /// their own. Values that already own their storage are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let frame = CheetahString::from("x".repeat(4096));
+    /// let view = frame.substring(100, 140);
+    /// let owned = view.detach();
+    /// drop(frame);
+    /// assert_eq!(owned, view);
+    /// assert_ne!(owned.as_ptr(), view.as_ptr());
+    /// ```
+    #[inline]
+    pub fn detach(&self) -> Self {
+        if self.borrows_parent() {
+            Self::copy_str(self.as_str())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Consumes the value and returns it with storage sized to its contents.
+    ///
+    /// This is the by-value form of [`CheetahString::detach`]: values that
+    /// do not borrow a parent allocation or an adopted `bytes::Bytes` are
+    /// returned unchanged.
+    #[inline]
+    pub fn compact(self) -> Self {
+        if self.borrows_parent() {
+            Self::copy_str(self.as_str())
+        } else {
+            self
+        }
+    }
+
+    /// Returns whether the text lives in an allocation that may be larger
+    /// than the value: a window into a shared block, or an adopted
+    /// `bytes::Bytes`, which can be a slice of a bigger frame.
+    #[inline]
+    fn borrows_parent(&self) -> bool {
+        match &self.inner {
+            InnerString::Sliced(_) => true,
+            #[cfg(feature = "bytes")]
+            InnerString::Shared(shared) => shared.external().is_some(),
+            _ => false,
+        }
+    }
+
+    /// Converts the value to a different inline capacity.
+    ///
+    /// Static values and long shared values keep their storage, so the
+    /// conversion never copies text that is too long for the target's inline
+    /// buffer. Text that fits the target capacity is stored inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::{CheetahString, CheetahString47};
+    ///
+    /// let long = CheetahString::from("route-".repeat(16));
+    /// let wide: CheetahString47 = long.clone().into_capacity();
+    /// assert_eq!(wide, long);
+    /// assert_eq!(wide.as_ptr(), long.as_ptr());
+    /// ```
+    #[inline]
+    pub fn into_capacity<const M: usize>(self) -> CheetahStringN<M> {
+        if let Some(inline) = InlineStr::from_str(self.as_str()) {
+            if !matches!(self.inner, InnerString::Static(_)) {
+                return CheetahStringN {
+                    inner: InnerString::Inline(inline),
+                };
+            }
+        }
+
+        let inner = match self.inner {
+            InnerString::Inline(inline) => return CheetahStringN::copy_str(inline.as_str()),
+            InnerString::Static(s) => InnerString::Static(s),
+            InnerString::Shared(s) => InnerString::Shared(s),
+            InnerString::Sliced(s) => InnerString::Sliced(s),
+        };
+        CheetahStringN { inner }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::inline::INLINE_CAPACITY;
+    use crate::CheetahString;
 
     #[test]
     fn long_borrowed_str_uses_shared_storage() {
@@ -291,7 +609,7 @@ mod tests {
         let s = CheetahString::from_slice(&value);
 
         match &s.inner {
-            InnerString::Shared(inner) => assert_eq!(inner.as_ref(), value.as_str()),
+            InnerString::Shared(inner) => assert_eq!(inner.as_str(), value.as_str()),
             other => panic!(
                 "expected Shared for long borrowed input, got {:?}",
                 core::mem::discriminant(other)
@@ -323,7 +641,7 @@ mod tests {
         match &s.inner {
             InnerString::Shared(inner) => {
                 assert_eq!(inner.len(), INLINE_CAPACITY + 1);
-                assert!(inner.bytes().all(|byte| byte == b'a'));
+                assert!(inner.as_str().bytes().all(|byte| byte == b'a'));
             }
             other => panic!(
                 "expected Shared for long Vec<u8> conversion, got {:?}",
@@ -331,4 +649,76 @@ mod tests {
             ),
         }
     }
+
+    #[test]
+    fn substring_of_shared_value_borrows_parent_backing() {
+        let parent = CheetahString::from_slice(&"x".repeat(4096));
+        let window = parent.substring(10, 50);
+
+        match &window.inner {
+            InnerString::Sliced(slice) => {
+                assert_eq!(slice.start(), 10);
+                assert_eq!(slice.len(), 40);
+            }
+            other => panic!(
+                "expected Sliced for long shared substring, got {:?}",
+                core::mem::discriminant(other)
+            ),
+        }
+
+        let nested = window.substring(5, 35);
+        match &nested.inner {
+            InnerString::Sliced(slice) => assert_eq!(slice.start(), 15),
+            other => panic!(
+                "expected Sliced for nested substring, got {:?}",
+                core::mem::discriminant(other)
+            ),
+        }
+    }
+
+    #[test]
+    fn short_substring_uses_inline_storage() {
+        let parent = CheetahString::from_slice(&"x".repeat(4096));
+        let piece = parent.substring(0, INLINE_CAPACITY);
+
+        assert!(matches!(piece.inner, InnerString::Inline(_)));
+    }
+
+    #[test]
+    fn static_substring_stays_static() {
+        let parent = CheetahString::from_static_str(
+            "a static string longer than the twenty-three-byte inline boundary",
+        );
+        let piece = parent.substring(2, 40);
+
+        match &piece.inner {
+            InnerString::Static(s) => assert_eq!(*s, &parent.as_str()[2..40]),
+            other => panic!(
+                "expected Static for static substring, got {:?}",
+                core::mem::discriminant(other)
+            ),
+        }
+    }
+
+    #[test]
+    fn short_static_substring_stays_static() {
+        let parent = CheetahString::from_static_str("system-topic");
+        let piece = parent.substring(7, 12);
+
+        assert!(matches!(piece.inner, InnerString::Static("topic")));
+    }
+
+    #[test]
+    fn detach_releases_parent_backing() {
+        let parent = CheetahString::from_slice(&"x".repeat(4096));
+        let window = parent.substring(10, 50);
+
+        let detached = window.detach();
+        assert!(matches!(detached.inner, InnerString::Shared(_)));
+        assert_eq!(detached, window);
+
+        let compacted = window.compact();
+        assert!(matches!(compacted.inner, InnerString::Shared(_)));
+        assert_eq!(compacted.len(), 40);
+    }
 }
@@ -6,86 +6,91 @@ use core::ops::Deref;
 use core::str::{FromStr, Utf8Error};
 
 use super::repr::InnerString;
-use super::CheetahString;
+use super::staging::Staging;
+use super::CheetahStringN;
 use crate::inline::InlineStr;
+use crate::metrics::Constructor;
+#[cfg(doc)]
+use crate::CheetahString;
 
-impl Default for CheetahString {
+impl<const N: usize> Default for CheetahStringN<N> {
     fn default() -> Self {
-        CheetahString {
+        Self {
             inner: InnerString::Inline(InlineStr::empty()),
         }
     }
 }
 
-impl From<String> for CheetahString {
+impl<const N: usize> From<String> for CheetahStringN<N> {
     #[inline]
     fn from(s: String) -> Self {
-        CheetahString::from_string(s)
+        Self::from_string(s)
     }
 }
 
-impl From<Arc<String>> for CheetahString {
+impl<const N: usize> From<Arc<String>> for CheetahStringN<N> {
     #[inline]
     fn from(s: Arc<String>) -> Self {
-        CheetahString::from_arc_string(s)
+        Self::from_arc_string(s)
     }
 }
 
-impl<'a> From<&'a str> for CheetahString {
+impl<'a, const N: usize> From<&'a str> for CheetahStringN<N> {
     #[inline]
     fn from(s: &'a str) -> Self {
-        CheetahString::from_slice(s)
+        Self::from_slice(s)
     }
 }
 
-impl<'a> TryFrom<&'a [u8]> for CheetahString {
+impl<'a, const N: usize> TryFrom<&'a [u8]> for CheetahStringN<N> {
     type Error = Utf8Error;
 
     #[inline]
     fn try_from(b: &'a [u8]) -> Result<Self, Self::Error> {
-        CheetahString::try_from_bytes(b)
+        Self::try_from_bytes(b)
     }
 }
 
-impl FromStr for CheetahString {
+impl<const N: usize> FromStr for CheetahStringN<N> {
     type Err = ParseError;
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(CheetahString::from_slice(s))
+        Ok(Self::from_slice(s))
     }
 }
 
-impl TryFrom<Vec<u8>> for CheetahString {
+impl<const N: usize> TryFrom<Vec<u8>> for CheetahStringN<N> {
     type Error = Utf8Error;
 
     #[inline]
     fn try_from(v: Vec<u8>) -> Result<Self, Self::Error> {
-        CheetahString::try_from_vec(v)
+        Self::try_from_vec(v)
     }
 }
 
-impl From<Cow<'static, str>> for CheetahString {
+impl<const N: usize> From<Cow<'static, str>> for CheetahStringN<N> {
     #[inline]
     fn from(cow: Cow<'static, str>) -> Self {
         match cow {
-            Cow::Borrowed(s) => CheetahString::from_static_str(s),
-            Cow::Owned(s) => CheetahString::from_string(s),
+            Cow::Borrowed(s) => Self::from_static_str(s),
+            Cow::Owned(s) => Self::from_string(s),
         }
     }
 }
 
-impl From<Cow<'_, String>> for CheetahString {
+impl<const N: usize> From<Cow<'_, String>> for CheetahStringN<N> {
     #[inline]
     fn from(cow: Cow<'_, String>) -> Self {
         match cow {
-            Cow::Borrowed(s) => CheetahString::from_slice(s),
-            Cow::Owned(s) => CheetahString::from_string(s),
+            Cow::Borrowed(s) => Self::from_slice(s),
+            Cow::Owned(s) => Self::from_string(s),
         }
     }
 }
 
-impl From<char> for CheetahString {
-    /// Allocates an owned [`CheetahString`] from a single character.
+impl<const N: usize> From<char> for CheetahStringN<N> {
+    /// Creates a [`CheetahString`] from a single character without
+    /// allocating.
     ///
     /// # Example
     /// ```rust
@@ -96,80 +101,153 @@ impl From<char> for CheetahString {
     /// ```
     #[inline]
     fn from(c: char) -> Self {
-        CheetahString::from_string(c.to_string())
+        let mut inline = InlineStr::empty();
+        let value = if inline.try_push(c) {
+            Self {
+                inner: InnerString::Inline(inline),
+            }
+        } else {
+            // Only capacities below four bytes can miss here.
+            Self::copy_str(c.encode_utf8(&mut [0; 4]))
+        };
+        value.counted(Constructor::FromString)
     }
 }
 
-impl<'a> FromIterator<&'a char> for CheetahString {
+impl<'a, const N: usize> FromIterator<&'a char> for CheetahStringN<N> {
     #[inline]
-    fn from_iter<T: IntoIterator<Item = &'a char>>(iter: T) -> CheetahString {
-        let mut buf = String::new();
-        buf.extend(iter);
-        CheetahString::from_string(buf)
+    fn from_iter<T: IntoIterator<Item = &'a char>>(iter: T) -> Self {
+        let mut buf = Staging::with_capacity(0);
+        iter.into_iter().for_each(|&ch| buf.push(ch));
+        buf.finish()
     }
 }
 
-impl<'a> FromIterator<&'a str> for CheetahString {
-    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> CheetahString {
-        let mut buf = String::new();
-        buf.extend(iter);
-        CheetahString::from_string(buf)
+impl<'a, const N: usize> FromIterator<&'a str> for CheetahStringN<N> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut buf = Staging::with_capacity(0);
+        iter.into_iter().for_each(|piece| buf.push_str(piece));
+        buf.finish()
     }
 }
 
-impl FromIterator<String> for CheetahString {
+impl<const N: usize> FromIterator<String> for CheetahStringN<N> {
     #[inline]
     fn from_iter<T: IntoIterator<Item = String>>(iter: T) -> Self {
-        let mut buf = String::new();
-        buf.extend(iter);
-        CheetahString::from_string(buf)
+        let mut buf = Staging::with_capacity(0);
+        iter.into_iter().for_each(|piece| buf.push_str(&piece));
+        buf.finish()
     }
 }
 
-impl<'a> FromIterator<&'a String> for CheetahString {
+impl<'a, const N: usize> FromIterator<&'a String> for CheetahStringN<N> {
     #[inline]
     fn from_iter<T: IntoIterator<Item = &'a String>>(iter: T) -> Self {
-        let mut buf = String::new();
-        buf.extend(iter.into_iter().map(|s| s.as_str()));
-        CheetahString::from_string(buf)
+        let mut buf = Staging::with_capacity(0);
+        iter.into_iter().for_each(|piece| buf.push_str(piece));
+        buf.finish()
     }
 }
 
 #[cfg(feature = "bytes")]
-impl TryFrom<bytes::Bytes> for CheetahString {
+impl<const N: usize> TryFrom<bytes::Bytes> for CheetahStringN<N> {
     type Error = crate::FromUtf8BytesError;
 
     #[inline]
     fn try_from(b: bytes::Bytes) -> Result<Self, Self::Error> {
-        CheetahString::try_copy_from_bytes(b)
+        Self::try_from_shared_bytes(b)
     }
 }
 
-impl From<&CheetahString> for CheetahString {
+#[cfg(feature = "bytes")]
+impl<const N: usize> CheetahStringN<N> {
+    /// Converts this value into `bytes::Bytes` without copying long text.
+    ///
+    /// Static values use `Bytes::from_static`, shared values hand their
+    /// reference-counted backing to `Bytes` as its owner, and values created
+    /// from `Bytes` return the original buffer. Only inline values are
+    /// copied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let value = CheetahString::from("orders-".repeat(8));
+    /// let pointer = value.as_ptr();
+    /// let bytes = value.into_bytes();
+    /// assert_eq!(bytes.as_ptr(), pointer);
+    /// ```
     #[inline]
-    fn from(s: &CheetahString) -> Self {
+    pub fn into_bytes(self) -> bytes::Bytes {
+        match self.inner {
+            InnerString::Inline(inline) => bytes::Bytes::copy_from_slice(inline.as_bytes()),
+            InnerString::Static(s) => bytes::Bytes::from_static(s.as_bytes()),
+            InnerString::Shared(s) => match s.external() {
+                Some(b) => b.clone(),
+                None => bytes::Bytes::from_owner(s),
+            },
+            InnerString::Sliced(s) => match s.backing().external() {
+                Some(b) => b.slice(s.start()..s.start() + s.len()),
+                None => bytes::Bytes::from_owner(s),
+            },
+        }
+    }
+
+    /// Returns a `bytes::Bytes` view of this value without copying long
+    /// text.
+    ///
+    /// This is equivalent to `self.clone().into_bytes()`.
+    #[inline]
+    pub fn to_bytes(&self) -> bytes::Bytes {
+        self.clone().into_bytes()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<const N: usize> From<CheetahStringN<N>> for bytes::Bytes {
+    #[inline]
+    fn from(s: CheetahStringN<N>) -> Self {
+        s.into_bytes()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<const N: usize> From<CheetahStringN<N>> for crate::CheetahBytes {
+    #[inline]
+    fn from(s: CheetahStringN<N>) -> Self {
+        crate::CheetahBytes::from_bytes(s.into_bytes())
+    }
+}
+
+impl<const N: usize> From<&CheetahStringN<N>> for CheetahStringN<N> {
+    #[inline]
+    fn from(s: &CheetahStringN<N>) -> Self {
         s.clone()
     }
 }
 
-impl From<CheetahString> for String {
+impl<const N: usize> From<CheetahStringN<N>> for String {
     #[inline]
-    fn from(s: CheetahString) -> Self {
+    fn from(s: CheetahStringN<N>) -> Self {
         match s {
-            CheetahString {
+            CheetahStringN {
                 inner: InnerString::Inline(inline),
             } => inline.into_string(),
-            CheetahString {
+            CheetahStringN {
                 inner: InnerString::Static(s),
             } => s.to_string(),
-            CheetahString {
+            CheetahStringN {
                 inner: InnerString::Shared(s),
-            } => s.to_string(),
+            } => s.as_str().to_string(),
+            CheetahStringN {
+                inner: InnerString::Sliced(s),
+            } => s.as_str().to_string(),
         }
     }
 }
 
-impl Deref for CheetahString {
+impl<const N: usize> Deref for CheetahStringN<N> {
     type Target = str;
 
     #[inline]
@@ -178,30 +256,30 @@ impl Deref for CheetahString {
     }
 }
 
-impl AsRef<str> for CheetahString {
+impl<const N: usize> AsRef<str> for CheetahStringN<N> {
     #[inline]
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
-impl AsRef<[u8]> for CheetahString {
+impl<const N: usize> AsRef<[u8]> for CheetahStringN<N> {
     #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
 }
 
-impl AsRef<CheetahString> for CheetahString {
+impl<const N: usize> AsRef<CheetahStringN<N>> for CheetahStringN<N> {
     #[inline]
-    fn as_ref(&self) -> &CheetahString {
+    fn as_ref(&self) -> &Self {
         self
     }
 }
 
-impl From<&String> for CheetahString {
+impl<const N: usize> From<&String> for CheetahStringN<N> {
     #[inline]
     fn from(s: &String) -> Self {
-        CheetahString::from_slice(s)
+        Self::from_slice(s)
     }
 }
@@ -0,0 +1,57 @@
+use core::fmt;
+
+use super::staging::Staging;
+use super::CheetahStringN;
+use crate::metrics::Constructor;
+#[cfg(doc)]
+use crate::CheetahString;
+
+impl<const N: usize> CheetahStringN<N> {
+    /// Formats `args` into a new value.
+    ///
+    /// Every argument is formatted exactly once. Output that fits inline
+    /// never touches the heap; longer output spills once into a growable
+    /// buffer and is then frozen into a right-sized shared allocation. This
+    /// is the function behind [`crate::cheetah_format!`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error, like
+    /// `format!`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let queue_id = 7;
+    /// let key = CheetahString::from_fmt(format_args!("orders-{queue_id}"));
+    /// assert_eq!(key, "orders-7");
+    /// ```
+    #[inline]
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        Self::render(args).counted(Constructor::Format)
+    }
+
+    /// Concatenates `parts` with at most one allocation.
+    ///
+    /// Support function for [`crate::cheetah_concat!`]; not part of the
+    /// public API.
+    #[doc(hidden)]
+    #[inline]
+    pub fn __from_parts(parts: &[&str]) -> Self {
+        Self::from_parts(parts).counted(Constructor::Concat)
+    }
+
+    fn render(args: fmt::Arguments<'_>) -> Self {
+        if let Some(text) = args.as_str() {
+            return Self::from_static_str(text);
+        }
+
+        // Each argument is formatted exactly once, so `Display` impls that
+        // are costly or not deterministic are never run again.
+        let mut out = Staging::with_capacity(0);
+        fmt::write(&mut out, args).expect("a formatting trait implementation returned an error");
+        out.finish()
+    }
+}
@@ -0,0 +1,90 @@
+use core::iter;
+
+use super::CheetahStringN;
+use crate::metrics::Constructor;
+#[cfg(doc)]
+use crate::CheetahString;
+
+impl<const N: usize> CheetahStringN<N> {
+    /// Joins `parts` with `sep` between each pair.
+    ///
+    /// The total length is measured first, so the result is written inline
+    /// when it fits and otherwise into a single right-sized shared
+    /// allocation. A single part is returned as a clone, sharing its
+    /// backing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let parts = [CheetahString::from("orders"), CheetahString::from("group-a")];
+    /// assert_eq!(CheetahString::join("@", &parts), "orders@group-a");
+    /// ```
+    pub fn join<'a, I>(sep: &str, parts: I) -> Self
+    where
+        I: IntoIterator<Item = &'a CheetahStringN<N>>,
+        I::IntoIter: Clone,
+    {
+        let mut parts = parts.into_iter();
+        let mut probe = parts.clone();
+        let value = match (probe.next(), probe.next()) {
+            (None, _) => Self::empty(),
+            // A clone is derived from an existing value, so it is not counted.
+            (Some(only), None) => return only.clone(),
+            (Some(first), Some(_)) => {
+                parts.next();
+                let rest = parts.flat_map(|part| [sep, part.as_str()]);
+                Self::from_pieces(iter::once(first.as_str()).chain(rest))
+            }
+        };
+        value.counted(Constructor::Concat)
+    }
+
+    /// Concatenates `parts` without a separator.
+    ///
+    /// This is [`CheetahString::join`] with an empty separator: the result
+    /// is written inline or into one shared allocation, and a single part is
+    /// returned as a clone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let parts = [CheetahString::from("retry-"), CheetahString::from("3")];
+    /// assert_eq!(CheetahString::concat(&parts), "retry-3");
+    /// ```
+    #[inline]
+    pub fn concat(parts: &[CheetahStringN<N>]) -> Self {
+        Self::join("", parts)
+    }
+}
+
+/// Joins slices of [`CheetahString`] values.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{CheetahString, JoinCheetah};
+///
+/// let tags = vec![CheetahString::from("a"), CheetahString::from("b")];
+/// assert_eq!(tags.join_cheetah(","), "a,b");
+/// ```
+pub trait JoinCheetah {
+    /// The joined value.
+    type Output;
+
+    /// Joins the elements with `sep` between each pair, measuring the total
+    /// length first so at most one allocation is made.
+    fn join_cheetah(&self, sep: &str) -> Self::Output;
+}
+
+impl<const N: usize> JoinCheetah for [CheetahStringN<N>] {
+    type Output = CheetahStringN<N>;
+
+    #[inline]
+    fn join_cheetah(&self, sep: &str) -> CheetahStringN<N> {
+        CheetahStringN::join(sep, self)
+    }
+}
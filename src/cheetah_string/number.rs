@@ -0,0 +1,259 @@
+use core::fmt::{self, Write};
+
+use super::staging::Staging;
+use super::CheetahStringN;
+use crate::metrics::Constructor;
+use crate::num;
+#[cfg(doc)]
+use crate::{CheetahString, Error};
+
+macro_rules! unsigned_constructors {
+    ($($name:ident => $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Returns the decimal text of a `", stringify!($ty), "`.")]
+            ///
+            /// Values up to 1024 use a static table; others are written
+            /// straight into inline storage.
+            #[inline]
+            pub fn $name(value: $ty) -> Self {
+                Self::from_unsigned(value as u64).counted(Constructor::Number)
+            }
+        )*
+    };
+}
+
+macro_rules! signed_constructors {
+    ($($name:ident => $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Returns the decimal text of an `", stringify!($ty), "`.")]
+            ///
+            /// Values from 0 to 1024 use a static table; others are written
+            /// straight into inline storage.
+            #[inline]
+            pub fn $name(value: $ty) -> Self {
+                Self::from_signed(value as i64).counted(Constructor::Number)
+            }
+        )*
+    };
+}
+
+macro_rules! parsers {
+    ($($name:ident => $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Parses the text as a decimal `", stringify!($ty), "`.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::InvalidNumber`] with the byte index of the
+            /// first character that is not part of the number, or
+            /// [`Error::NumberOverflow`] with the index of the digit at which
+            /// the value stops fitting.
+            #[inline]
+            pub fn $name(&self) -> crate::Result<$ty> {
+                num::$name(self.as_str())
+            }
+        )*
+    };
+}
+
+impl<const N: usize> CheetahStringN<N> {
+    unsigned_constructors! {
+        from_u8 => u8,
+        from_u16 => u16,
+        from_u32 => u32,
+        from_u64 => u64,
+        from_usize => usize,
+    }
+
+    signed_constructors! {
+        from_i8 => i8,
+        from_i16 => i16,
+        from_i32 => i32,
+        from_i64 => i64,
+        from_isize => isize,
+    }
+
+    /// Returns the decimal text of a `u128`.
+    ///
+    /// Values up to 1024 use a static table; others are written straight
+    /// into inline storage when they fit.
+    #[inline]
+    pub fn from_u128(value: u128) -> Self {
+        let value = match u64::try_from(value) {
+            Ok(value) => Self::from_unsigned(value),
+            Err(_) => {
+                let mut buf = [0; num::MAX_DEC128_LEN];
+                Self::copy_str(num::format_u128(value, &mut buf))
+            }
+        };
+        value.counted(Constructor::Number)
+    }
+
+    /// Returns the decimal text of an `i128`.
+    ///
+    /// Values from 0 to 1024 use a static table; others are written straight
+    /// into inline storage when they fit.
+    #[inline]
+    pub fn from_i128(value: i128) -> Self {
+        let value = match i64::try_from(value) {
+            Ok(value) => Self::from_signed(value),
+            Err(_) => {
+                let mut buf = [0; num::MAX_DEC128_LEN];
+                Self::copy_str(num::format_i128(value, &mut buf))
+            }
+        };
+        value.counted(Constructor::Number)
+    }
+
+    /// Returns `"true"` or `"false"` without allocating.
+    #[inline]
+    pub const fn from_bool(value: bool) -> Self {
+        Self::from_static_str(if value { "true" } else { "false" })
+    }
+
+    /// Returns the shortest decimal text that parses back to the same `f32`,
+    /// matching `value.to_string()`.
+    ///
+    /// Results that fit inline do not allocate.
+    #[inline]
+    pub fn from_f32(value: f32) -> Self {
+        Self::from_display(value).counted(Constructor::Number)
+    }
+
+    /// Returns the shortest decimal text that parses back to the same `f64`,
+    /// matching `value.to_string()`.
+    ///
+    /// Results that fit inline do not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// assert_eq!(CheetahString::from_f64(0.1), "0.1");
+    /// assert_eq!(CheetahString::from(-2.5f64), "-2.5");
+    /// ```
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self::from_display(value).counted(Constructor::Number)
+    }
+
+    parsers! {
+        parse_u8 => u8,
+        parse_u16 => u16,
+        parse_u32 => u32,
+        parse_u64 => u64,
+        parse_u128 => u128,
+        parse_usize => usize,
+        parse_i8 => i8,
+        parse_i16 => i16,
+        parse_i32 => i32,
+        parse_i64 => i64,
+        parse_i128 => i128,
+        parse_isize => isize,
+    }
+
+    /// Parses the text as an `f32`, accepting the same syntax as
+    /// `str::parse::<f32>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidNumber`] with the byte index of the first
+    /// character that breaks the float syntax.
+    #[inline]
+    pub fn parse_f32(&self) -> crate::Result<f32> {
+        num::parse_f32(self.as_str())
+    }
+
+    /// Parses the text as an `f64`, accepting the same syntax as
+    /// `str::parse::<f64>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidNumber`] with the byte index of the first
+    /// character that breaks the float syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::{CheetahString, Error};
+    ///
+    /// assert_eq!(CheetahString::from("2.5e3").parse_f64(), Ok(2500.0));
+    /// assert_eq!(
+    ///     CheetahString::from("2.5x").parse_f64(),
+    ///     Err(Error::InvalidNumber { index: 3 })
+    /// );
+    /// ```
+    #[inline]
+    pub fn parse_f64(&self) -> crate::Result<f64> {
+        num::parse_f64(self.as_str())
+    }
+
+    /// Parses `"true"` or `"false"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidBool`] with the byte index at which the text
+    /// stops matching either word.
+    #[inline]
+    pub fn parse_bool(&self) -> crate::Result<bool> {
+        num::parse_bool(self.as_str())
+    }
+
+    fn from_unsigned(value: u64) -> Self {
+        if let Some(text) = num::small_number(u128::from(value)) {
+            return Self::from_static_str(text);
+        }
+
+        let mut buf = [0; num::MAX_DEC_LEN];
+        Self::copy_str(num::format_u64(value, &mut buf))
+    }
+
+    fn from_signed(value: i64) -> Self {
+        if let Ok(value) = u64::try_from(value) {
+            return Self::from_unsigned(value);
+        }
+
+        let mut buf = [0; num::MAX_DEC_LEN];
+        Self::copy_str(num::format_i64(value, &mut buf))
+    }
+
+    fn from_display(value: impl fmt::Display) -> Self {
+        let mut out = Staging::with_capacity(0);
+        // Writing into a staging buffer never fails.
+        let _ = write!(out, "{value}");
+        out.finish()
+    }
+}
+
+macro_rules! from_impls {
+    ($($ty:ty => $name:ident),* $(,)?) => {
+        $(
+            impl<const N: usize> From<$ty> for CheetahStringN<N> {
+                #[doc = concat!("Converts with [`CheetahString::", stringify!($name), "`].")]
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Self::$name(value)
+                }
+            }
+        )*
+    };
+}
+
+from_impls! {
+    u8 => from_u8,
+    u16 => from_u16,
+    u32 => from_u32,
+    u64 => from_u64,
+    u128 => from_u128,
+    usize => from_usize,
+    i8 => from_i8,
+    i16 => from_i16,
+    i32 => from_i32,
+    i64 => from_i64,
+    i128 => from_i128,
+    isize => from_isize,
+    bool => from_bool,
+    f32 => from_f32,
+    f64 => from_f64,
+}
@@ -1,58 +1,450 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
 use alloc::string::String;
 use core::str;
 
-// Sealed trait pattern to support both &str and char in starts_with/ends_with/contains.
+use super::split::Split;
+use super::CheetahStringN;
+
+// Sealed trait pattern mirroring the standard library `Pattern` implementors.
 mod private {
+    use super::CheetahStringN;
+    use alloc::borrow::Cow;
+    use alloc::boxed::Box;
     use alloc::string::String;
 
     pub trait Sealed {}
     impl Sealed for char {}
     impl Sealed for &str {}
+    impl Sealed for &&str {}
     impl Sealed for &String {}
+    impl<const N: usize> Sealed for &CheetahStringN<N> {}
+    impl Sealed for String {}
+    impl Sealed for Box<str> {}
+    impl Sealed for Cow<'_, str> {}
+    impl<const N: usize> Sealed for CheetahStringN<N> {}
+    impl Sealed for &[char] {}
+    impl<const N: usize> Sealed for [char; N] {}
+    impl<const N: usize> Sealed for &[char; N] {}
+    impl<F: FnMut(char) -> bool> Sealed for F {}
 
     pub trait SplitSealed {}
-    impl SplitSealed for char {}
-    impl SplitSealed for &str {}
+    impl<P: Sealed> SplitSealed for P {}
 }
 
-/// A pattern that can be used with `starts_with` and `ends_with` methods.
-pub trait StrPattern: private::Sealed {
+/// A pattern accepted by `CheetahString` search, split, and match methods.
+///
+/// This mirrors the implementors of the standard library's unstable
+/// `Pattern` trait: `char`, `&str`, `&&str`, `&String`, `&[char]`,
+/// `[char; N]`, `&[char; N]`, and `FnMut(char) -> bool`, plus
+/// `&CheetahString`. Owned `String`, `Box<str>`, `Cow<str>` and
+/// `CheetahString` needles are accepted too, so callers that passed them to
+/// the v2 `AsRef<str>` search methods keep compiling. String searches use the
+/// `memchr`/`memmem` backend.
+pub trait StrPattern: private::Sealed + Sized {
+    #[doc(hidden)]
+    type Matcher: Matcher;
+
     #[doc(hidden)]
-    fn as_str_pattern(&self) -> StrPatternImpl<'_>;
+    fn into_matcher(self) -> Self::Matcher;
+}
+
+/// Search strategy produced by a [`StrPattern`].
+///
+/// Matches are byte ranges on UTF-8 character boundaries.
+#[doc(hidden)]
+pub trait Matcher {
+    /// Returns the leftmost match in `haystack`.
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the rightmost match in `haystack`.
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the match length if `haystack` starts with a match.
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize>;
+
+    /// Returns the match length if `haystack` ends with a match.
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize>;
+}
+
+/// Marker for matchers whose forward and reverse matches always agree.
+///
+/// Only character matchers qualify; string patterns can overlap (`"aa"` in
+/// `"aaa"`), so their split iterators are not double-ended, as in `std`.
+#[doc(hidden)]
+pub trait DoubleEndedMatcher: Matcher {}
+
+/// Matches a single character.
+#[doc(hidden)]
+pub struct CharMatcher {
+    ch: char,
+    encoded: [u8; 4],
+    len: usize,
+}
+
+impl CharMatcher {
+    #[inline]
+    fn new(ch: char) -> Self {
+        let mut encoded = [0; 4];
+        let len = ch.encode_utf8(&mut encoded).len();
+        Self { ch, encoded, len }
+    }
+
+    #[inline]
+    fn bytes(&self) -> &[u8] {
+        &self.encoded[..self.len]
+    }
+}
+
+impl Matcher for CharMatcher {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        crate::search::find_bytes(haystack.as_bytes(), self.bytes())
+            .map(|start| (start, start + self.len))
+    }
+
+    #[inline]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        crate::search::rfind_bytes(haystack.as_bytes(), self.bytes())
+            .map(|start| (start, start + self.len))
+    }
+
+    #[inline]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(self.ch).then_some(self.len)
+    }
+
+    #[inline]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(self.ch).then_some(self.len)
+    }
+}
+
+impl DoubleEndedMatcher for CharMatcher {}
+
+/// Matches a string; the empty string matches at every character boundary.
+#[doc(hidden)]
+pub struct StrMatcher<'p> {
+    needle: &'p str,
+}
+
+impl Matcher for StrMatcher<'_> {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        crate::search::find_bytes(haystack.as_bytes(), self.needle.as_bytes())
+            .map(|start| (start, start + self.needle.len()))
+    }
+
+    #[inline]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        crate::search::rfind_bytes(haystack.as_bytes(), self.needle.as_bytes())
+            .map(|start| (start, start + self.needle.len()))
+    }
+
+    #[inline]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
+        {
+            if self.needle.len() >= crate::simd::SIMD_THRESHOLD {
+                return crate::simd::starts_with_bytes(haystack.as_bytes(), self.needle.as_bytes())
+                    .then_some(self.needle.len());
+            }
+        }
+
+        haystack
+            .starts_with(self.needle)
+            .then_some(self.needle.len())
+    }
+
+    #[inline]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
+        {
+            if self.needle.len() >= crate::simd::SIMD_THRESHOLD {
+                return crate::simd::ends_with_bytes(haystack.as_bytes(), self.needle.as_bytes())
+                    .then_some(self.needle.len());
+            }
+        }
+
+        haystack.ends_with(self.needle).then_some(self.needle.len())
+    }
+}
+
+/// Matches a string owned by the pattern.
+#[doc(hidden)]
+pub struct OwnedStrMatcher<S>(S);
+
+impl<S: AsRef<str>> OwnedStrMatcher<S> {
+    #[inline]
+    fn borrowed(&self) -> StrMatcher<'_> {
+        StrMatcher {
+            needle: self.0.as_ref(),
+        }
+    }
+}
+
+impl<S: AsRef<str>> Matcher for OwnedStrMatcher<S> {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.borrowed().find_in(haystack)
+    }
+
+    #[inline]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        self.borrowed().rfind_in(haystack)
+    }
+
+    #[inline]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.borrowed().prefix_len(haystack)
+    }
+
+    #[inline]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        self.borrowed().suffix_len(haystack)
+    }
 }
 
+/// Character predicate used by [`CharEqMatcher`].
 #[doc(hidden)]
-pub enum StrPatternImpl<'a> {
-    Char(char),
-    Str(&'a str),
+pub trait CharEq {
+    fn matches(&mut self, ch: char) -> bool;
+
+    /// Returns the matched ASCII bytes when every match is a single byte and
+    /// there are at most three of them, enabling `memchr2`/`memchr3`.
+    fn ascii_set(&self) -> Option<([u8; 3], usize)> {
+        None
+    }
 }
 
+impl<F: FnMut(char) -> bool> CharEq for F {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self(ch)
+    }
+}
+
+/// Any character from a borrowed set.
+#[doc(hidden)]
+pub struct CharSlice<'p>(&'p [char]);
+
+/// Any character from an owned set.
+#[doc(hidden)]
+pub struct CharArray<const N: usize>([char; N]);
+
+#[inline]
+fn ascii_set(chars: &[char]) -> Option<([u8; 3], usize)> {
+    if chars.is_empty() || chars.len() > 3 || !chars.iter().all(char::is_ascii) {
+        return None;
+    }
+
+    let mut set = [chars[0] as u8; 3];
+    for (slot, ch) in set.iter_mut().zip(chars) {
+        *slot = *ch as u8;
+    }
+    Some((set, chars.len()))
+}
+
+impl CharEq for CharSlice<'_> {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self.0.contains(&ch)
+    }
+
+    #[inline]
+    fn ascii_set(&self) -> Option<([u8; 3], usize)> {
+        ascii_set(self.0)
+    }
+}
+
+impl<const N: usize> CharEq for CharArray<N> {
+    #[inline]
+    fn matches(&mut self, ch: char) -> bool {
+        self.0.contains(&ch)
+    }
+
+    #[inline]
+    fn ascii_set(&self) -> Option<([u8; 3], usize)> {
+        ascii_set(&self.0)
+    }
+}
+
+/// Matches any single character accepted by a [`CharEq`].
+#[doc(hidden)]
+pub struct CharEqMatcher<C> {
+    eq: C,
+}
+
+impl<C: CharEq> Matcher for CharEqMatcher<C> {
+    #[inline]
+    fn find_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if let Some((set, len)) = self.eq.ascii_set() {
+            return crate::search::find_ascii_set(haystack.as_bytes(), &set[..len])
+                .map(|start| (start, start + 1));
+        }
+
+        haystack
+            .char_indices()
+            .find(|&(_, ch)| self.eq.matches(ch))
+            .map(|(start, ch)| (start, start + ch.len_utf8()))
+    }
+
+    #[inline]
+    fn rfind_in(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        if let Some((set, len)) = self.eq.ascii_set() {
+            return crate::search::rfind_ascii_set(haystack.as_bytes(), &set[..len])
+                .map(|start| (start, start + 1));
+        }
+
+        haystack
+            .char_indices()
+            .rev()
+            .find(|&(_, ch)| self.eq.matches(ch))
+            .map(|(start, ch)| (start, start + ch.len_utf8()))
+    }
+
+    #[inline]
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        let ch = haystack.chars().next()?;
+        self.eq.matches(ch).then(|| ch.len_utf8())
+    }
+
+    #[inline]
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        let ch = haystack.chars().next_back()?;
+        self.eq.matches(ch).then(|| ch.len_utf8())
+    }
+}
+
+impl<C: CharEq> DoubleEndedMatcher for CharEqMatcher<C> {}
+
 impl StrPattern for char {
+    type Matcher = CharMatcher;
+
     #[inline]
-    fn as_str_pattern(&self) -> StrPatternImpl<'_> {
-        StrPatternImpl::Char(*self)
+    fn into_matcher(self) -> Self::Matcher {
+        CharMatcher::new(self)
     }
 }
 
-impl StrPattern for &str {
+impl<'p> StrPattern for &'p str {
+    type Matcher = StrMatcher<'p>;
+
     #[inline]
-    fn as_str_pattern(&self) -> StrPatternImpl<'_> {
-        StrPatternImpl::Str(self)
+    fn into_matcher(self) -> Self::Matcher {
+        StrMatcher { needle: self }
     }
 }
 
-impl StrPattern for &String {
+impl<'p> StrPattern for &&'p str {
+    type Matcher = StrMatcher<'p>;
+
     #[inline]
-    fn as_str_pattern(&self) -> StrPatternImpl<'_> {
-        StrPatternImpl::Str(self.as_str())
+    fn into_matcher(self) -> Self::Matcher {
+        StrMatcher { needle: self }
     }
 }
 
-/// A compatibility pattern whose iterator type exposes its capabilities.
+impl<'p> StrPattern for &'p String {
+    type Matcher = StrMatcher<'p>;
+
+    #[inline]
+    fn into_matcher(self) -> Self::Matcher {
+        StrMatcher {
+            needle: self.as_str(),
+        }
+    }
+}
+
+impl<'p, const N: usize> StrPattern for &'p CheetahStringN<N> {
+    type Matcher = StrMatcher<'p>;
+
+    #[inline]
+    fn into_matcher(self) -> Self::Matcher {
+        StrMatcher {
+            needle: self.as_str(),
+        }
+    }
+}
+
+macro_rules! owned_str_pattern {
+    ($([$($params:tt)*] $ty:ty),* $(,)?) => {$(
+        impl<$($params)*> StrPattern for $ty {
+            type Matcher = OwnedStrMatcher<Self>;
+
+            #[inline]
+            fn into_matcher(self) -> Self::Matcher {
+                OwnedStrMatcher(self)
+            }
+        }
+
+        impl<'a, $($params)*> SplitPattern<'a> for $ty {
+            type Iter = Split<'a, Self>;
+
+            #[inline]
+            fn split_pattern(self, value: &'a str) -> Self::Iter {
+                Split::new(value, self)
+            }
+        }
+    )*};
+}
+
+owned_str_pattern! {
+    [] String,
+    [] Box<str>,
+    ['p] Cow<'p, str>,
+    [const N: usize] CheetahStringN<N>,
+}
+
+impl<'p> StrPattern for &'p [char] {
+    type Matcher = CharEqMatcher<CharSlice<'p>>;
+
+    #[inline]
+    fn into_matcher(self) -> Self::Matcher {
+        CharEqMatcher {
+            eq: CharSlice(self),
+        }
+    }
+}
+
+impl<const N: usize> StrPattern for [char; N] {
+    type Matcher = CharEqMatcher<CharArray<N>>;
+
+    #[inline]
+    fn into_matcher(self) -> Self::Matcher {
+        CharEqMatcher {
+            eq: CharArray(self),
+        }
+    }
+}
+
+impl<'p, const N: usize> StrPattern for &'p [char; N] {
+    type Matcher = CharEqMatcher<CharSlice<'p>>;
+
+    #[inline]
+    fn into_matcher(self) -> Self::Matcher {
+        CharEqMatcher {
+            eq: CharSlice(self),
+        }
+    }
+}
+
+impl<F: FnMut(char) -> bool> StrPattern for F {
+    type Matcher = CharEqMatcher<F>;
+
+    #[inline]
+    fn into_matcher(self) -> Self::Matcher {
+        CharEqMatcher { eq: self }
+    }
+}
+
+/// A split pattern whose iterator type exposes its capabilities.
 ///
-/// Unlike the legacy v2 erased iterator, this associated type cannot promise
-/// reverse iteration for string patterns and therefore cannot panic from an
-/// unsupported `next_back()` call.
+/// `char` keeps the standard double-ended `str::Split` and `&str` keeps the
+/// forward-only [`SplitStr`]. Every other [`StrPattern`] produces a
+/// [`Split`], which is double-ended only for character patterns, so an
+/// unsupported `next_back()` call fails at compile time instead of
+/// panicking.
 pub trait SplitPattern<'a>: private::SplitSealed {
     /// Iterator produced for this pattern.
     type Iter: Iterator<Item = &'a str>;
@@ -79,6 +471,69 @@ impl<'a, 'p> SplitPattern<'a> for &'p str {
     }
 }
 
+impl<'a, 'b, 'p> SplitPattern<'a> for &'b &'p str {
+    type Iter = Split<'a, &'b &'p str>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
+impl<'a, 'p> SplitPattern<'a> for &'p String {
+    type Iter = Split<'a, &'p String>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
+impl<'a, 'p, const N: usize> SplitPattern<'a> for &'p CheetahStringN<N> {
+    type Iter = Split<'a, &'p CheetahStringN<N>>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
+impl<'a, 'p> SplitPattern<'a> for &'p [char] {
+    type Iter = Split<'a, &'p [char]>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
+impl<'a, const N: usize> SplitPattern<'a> for [char; N] {
+    type Iter = Split<'a, [char; N]>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
+impl<'a, 'p, const N: usize> SplitPattern<'a> for &'p [char; N] {
+    type Iter = Split<'a, &'p [char; N]>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
+impl<'a, F: FnMut(char) -> bool> SplitPattern<'a> for F {
+    type Iter = Split<'a, F>;
+
+    #[inline]
+    fn split_pattern(self, value: &'a str) -> Self::Iter {
+        Split::new(value, self)
+    }
+}
+
 /// Helper struct for splitting strings by a string pattern.
 ///
 /// This iterator is intentionally forward-only because Rust's standard string
@@ -1,9 +1,24 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
 use core::str;
 
-use super::pattern::{SplitPattern, SplitStr, StrPattern, StrPatternImpl};
-use super::CheetahString;
+use super::pattern::{Matcher, SplitPattern, SplitStr, StrPattern};
+use super::repr::InnerString;
+use super::split::{
+    MatchIndices, Matches, OwnedPieces, RMatchIndices, RMatches, RSplit, RSplitN, RSplitTerminator,
+    SplitInclusive, SplitN, SplitTerminator,
+};
+use super::staging::Staging;
+use super::CheetahStringN;
+use crate::inline::InlineStr;
+use crate::search;
+use crate::{AsciiFolding, CaseFolding};
+#[cfg(doc)]
+use crate::{CheetahFinder, CheetahString};
 
-impl CheetahString {
+impl<const N: usize> CheetahStringN<N> {
     // Query methods - delegate to &str
 
     /// Returns `true` if the string starts with the given pattern.
@@ -23,19 +38,7 @@ impl CheetahString {
     /// ```
     #[inline]
     pub fn starts_with<P: StrPattern>(&self, pat: P) -> bool {
-        match pat.as_str_pattern() {
-            StrPatternImpl::Char(c) => self.as_str().starts_with(c),
-            StrPatternImpl::Str(s) => {
-                #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
-                {
-                    if s.len() >= crate::simd::SIMD_THRESHOLD {
-                        return crate::simd::starts_with_bytes(self.as_bytes(), s.as_bytes());
-                    }
-                }
-
-                self.as_str().starts_with(s)
-            }
-        }
+        pat.into_matcher().prefix_len(self.as_str()).is_some()
     }
 
     /// Returns `true` if the string starts with the given character.
@@ -71,19 +74,7 @@ impl CheetahString {
     /// ```
     #[inline]
     pub fn ends_with<P: StrPattern>(&self, pat: P) -> bool {
-        match pat.as_str_pattern() {
-            StrPatternImpl::Char(c) => self.as_str().ends_with(c),
-            StrPatternImpl::Str(s) => {
-                #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
-                {
-                    if s.len() >= crate::simd::SIMD_THRESHOLD {
-                        return crate::simd::ends_with_bytes(self.as_bytes(), s.as_bytes());
-                    }
-                }
-
-                self.as_str().ends_with(s)
-            }
-        }
+        pat.into_matcher().suffix_len(self.as_str()).is_some()
     }
 
     /// Returns `true` if the string ends with the given character.
@@ -118,12 +109,7 @@ impl CheetahString {
     /// ```
     #[inline]
     pub fn contains<P: StrPattern>(&self, pat: P) -> bool {
-        match pat.as_str_pattern() {
-            StrPatternImpl::Char(c) => self.as_str().contains(c),
-            StrPatternImpl::Str(s) => {
-                crate::search::find_bytes(self.as_bytes(), s.as_bytes()).is_some()
-            }
-        }
+        pat.into_matcher().find_in(self.as_str()).is_some()
     }
 
     /// Returns `true` if the string contains the given character.
@@ -154,11 +140,14 @@ impl CheetahString {
     /// let s = CheetahString::from("hello world");
     /// assert_eq!(s.find("world"), Some(6));
     /// assert_eq!(s.find("xyz"), None);
+    /// assert_eq!(s.find(&[' ', 'o'][..]), Some(4));
+    /// assert_eq!(s.find(char::is_whitespace), Some(5));
     /// ```
     #[inline]
-    pub fn find<P: AsRef<str>>(&self, pat: P) -> Option<usize> {
-        let pat = pat.as_ref();
-        crate::search::find_bytes(self.as_bytes(), pat.as_bytes())
+    pub fn find<P: StrPattern>(&self, pat: P) -> Option<usize> {
+        pat.into_matcher()
+            .find_in(self.as_str())
+            .map(|(start, _)| start)
     }
 
     /// Returns the byte index of the last occurrence of the pattern, or `None` if not found.
@@ -170,10 +159,116 @@ impl CheetahString {
     ///
     /// let s = CheetahString::from("hello hello");
     /// assert_eq!(s.rfind("hello"), Some(6));
+    /// assert_eq!(s.rfind(['h', 'e']), Some(7));
+    /// ```
+    #[inline]
+    pub fn rfind<P: StrPattern>(&self, pat: P) -> Option<usize> {
+        pat.into_matcher()
+            .rfind_in(self.as_str())
+            .map(|(start, _)| start)
+    }
+
+    /// Returns `true` if the text equals `other`, treating ASCII letters of
+    /// either case as equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("Content-Length");
+    /// assert!(s.eq_ignore_ascii_case("content-length"));
+    /// assert!(!s.eq_ignore_ascii_case("content_length"));
+    /// ```
+    #[inline]
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Compares the text with `other` as if ASCII letters were lowercase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    /// use std::cmp::Ordering;
+    ///
+    /// let s = CheetahString::from("Beta");
+    /// assert_eq!(s.cmp_ignore_ascii_case("alpha"), Ordering::Greater);
+    /// assert_eq!(s.cmp_ignore_ascii_case("BETA"), Ordering::Equal);
+    /// ```
+    #[inline]
+    pub fn cmp_ignore_ascii_case(&self, other: &str) -> Ordering {
+        AsciiFolding::fold_cmp(self.as_str(), other)
+    }
+
+    /// Returns `true` if the text starts with `prefix`, ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("X-Request-Id");
+    /// assert!(s.starts_with_ignore_ascii_case("x-request"));
+    /// ```
+    #[inline]
+    pub fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool {
+        let bytes = self.as_bytes();
+        bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+    }
+
+    /// Returns `true` if the text ends with `suffix`, ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("archive.TAR.GZ");
+    /// assert!(s.ends_with_ignore_ascii_case(".tar.gz"));
+    /// ```
+    #[inline]
+    pub fn ends_with_ignore_ascii_case(&self, suffix: &str) -> bool {
+        let bytes = self.as_bytes();
+        bytes.len() >= suffix.len()
+            && bytes[bytes.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+    }
+
+    /// Returns the byte index of the first occurrence of `needle`, ignoring
+    /// ASCII case.
+    ///
+    /// Non-ASCII characters must match exactly. Use
+    /// [`CheetahFinder::new_ignore_ascii_case`] to search many values for the
+    /// same needle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("tags=Urgent,Billing");
+    /// assert_eq!(s.find_ignore_ascii_case("billing"), Some(12));
+    /// assert_eq!(s.find_ignore_ascii_case("refund"), None);
+    /// ```
+    #[inline]
+    pub fn find_ignore_ascii_case(&self, needle: &str) -> Option<usize> {
+        search::find_ignore_ascii_case(self.as_bytes(), needle.as_bytes())
+    }
+
+    /// Returns `true` if the text contains `needle`, ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("tags=Urgent,Billing");
+    /// assert!(s.contains_ignore_ascii_case("URGENT"));
     /// ```
     #[inline]
-    pub fn rfind<P: AsRef<str>>(&self, pat: P) -> Option<usize> {
-        crate::search::rfind_bytes(self.as_bytes(), pat.as_ref().as_bytes())
+    pub fn contains_ignore_ascii_case(&self, needle: &str) -> bool {
+        self.find_ignore_ascii_case(needle).is_some()
     }
 
     /// Returns a string slice with leading and trailing whitespace removed.
@@ -221,6 +316,96 @@ impl CheetahString {
         self.as_str().trim_end()
     }
 
+    /// Returns the text after `prefix`, or `None` if it does not start with
+    /// a match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("%RETRY%orders");
+    /// assert_eq!(s.strip_prefix("%RETRY%"), Some("orders"));
+    /// assert_eq!(s.strip_prefix('x'), None);
+    /// ```
+    #[inline]
+    pub fn strip_prefix<P: StrPattern>(&self, prefix: P) -> Option<&str> {
+        let value = self.as_str();
+        let len = prefix.into_matcher().prefix_len(value)?;
+        Some(&value[len..])
+    }
+
+    /// Returns the text before `suffix`, or `None` if it does not end with a
+    /// match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("orders.bak");
+    /// assert_eq!(s.strip_suffix(".bak"), Some("orders"));
+    /// ```
+    #[inline]
+    pub fn strip_suffix<P: StrPattern>(&self, suffix: P) -> Option<&str> {
+        let value = self.as_str();
+        let len = suffix.into_matcher().suffix_len(value)?;
+        Some(&value[..value.len() - len])
+    }
+
+    /// Returns the text with all leading and trailing matches of `pat`
+    /// removed.
+    ///
+    /// Leading matches are removed first, then trailing matches of what
+    /// remains, so string patterns are accepted as well as characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("--orders--");
+    /// assert_eq!(s.trim_matches('-'), "orders");
+    /// assert_eq!(s.trim_matches(['-', 's']), "order");
+    /// ```
+    #[inline]
+    pub fn trim_matches<P: StrPattern>(&self, pat: P) -> &str {
+        let (start, end) = trimmed_range(self.as_str(), pat.into_matcher(), true, true);
+        &self.as_str()[start..end]
+    }
+
+    /// Returns the text with all leading matches of `pat` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("0042");
+    /// assert_eq!(s.trim_start_matches('0'), "42");
+    /// ```
+    #[inline]
+    pub fn trim_start_matches<P: StrPattern>(&self, pat: P) -> &str {
+        let (start, end) = trimmed_range(self.as_str(), pat.into_matcher(), true, false);
+        &self.as_str()[start..end]
+    }
+
+    /// Returns the text with all trailing matches of `pat` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("path///");
+    /// assert_eq!(s.trim_end_matches('/'), "path");
+    /// ```
+    #[inline]
+    pub fn trim_end_matches<P: StrPattern>(&self, pat: P) -> &str {
+        let (start, end) = trimmed_range(self.as_str(), pat.into_matcher(), false, true);
+        &self.as_str()[start..end]
+    }
+
     /// Splits the string by a character pattern.
     ///
     /// The returned iterator supports reverse iteration.
@@ -268,6 +453,10 @@ impl CheetahString {
     /// Splits with a v2-compatible pattern while retaining its concrete
     /// iterator capability in the return type.
     ///
+    /// Character patterns (`char`, `&[char]`, `[char; N]`, closures) produce
+    /// double-ended iterators; string patterns are forward-only, so
+    /// unsupported reverse iteration fails at compile time.
+    ///
     /// New code should use [`CheetahString::split_char`] or
     /// [`CheetahString::split_str`] for a self-documenting capability.
     #[deprecated(
@@ -282,177 +471,914 @@ impl CheetahString {
         pat.split_pattern(self.as_str())
     }
 
-    /// Returns an iterator over the lines of the string.
+    /// Splits by a pattern, yielding substrings in reverse order.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("line1\nline2\nline3");
-    /// let lines: Vec<&str> = s.lines().collect();
-    /// assert_eq!(lines, vec!["line1", "line2", "line3"]);
+    /// let s = CheetahString::from("a::b::c");
+    /// let parts: Vec<&str> = s.rsplit("::").collect();
+    /// assert_eq!(parts, vec!["c", "b", "a"]);
     /// ```
     #[inline]
-    pub fn lines(&self) -> impl Iterator<Item = &str> {
-        self.as_str().lines()
+    pub fn rsplit<P: StrPattern>(&self, pat: P) -> RSplit<'_, P> {
+        RSplit::new(self.as_str(), pat)
     }
 
-    /// Returns an iterator over the characters of the string.
+    /// Splits by a pattern into at most `n` substrings.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("hello");
-    /// let chars: Vec<char> = s.chars().collect();
-    /// assert_eq!(chars, vec!['h', 'e', 'l', 'l', 'o']);
-    /// let reversed: Vec<char> = s.chars().rev().collect();
-    /// assert_eq!(reversed, vec!['o', 'l', 'l', 'e', 'h']);
+    /// let s = CheetahString::from("KEYS a b c");
+    /// let parts: Vec<&str> = s.splitn(2, ' ').collect();
+    /// assert_eq!(parts, vec!["KEYS", "a b c"]);
     /// ```
     #[inline]
-    pub fn chars(&self) -> str::Chars<'_> {
-        self.as_str().chars()
+    pub fn splitn<P: StrPattern>(&self, n: usize, pat: P) -> SplitN<'_, P> {
+        SplitN::new(self.as_str(), n, pat)
     }
 
-    // Transformation methods - create new CheetahString
-
-    /// Returns a new `CheetahString` with all characters converted to uppercase.
+    /// Splits by a pattern from the end into at most `n` substrings.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("hello");
-    /// assert_eq!(s.to_uppercase(), "HELLO");
+    /// let s = CheetahString::from("a.b.c");
+    /// let parts: Vec<&str> = s.rsplitn(2, '.').collect();
+    /// assert_eq!(parts, vec!["c", "a.b"]);
     /// ```
     #[inline]
-    pub fn to_uppercase(&self) -> CheetahString {
-        CheetahString::from_string(self.as_str().to_uppercase())
+    pub fn rsplitn<P: StrPattern>(&self, n: usize, pat: P) -> RSplitN<'_, P> {
+        RSplitN::new(self.as_str(), n, pat)
     }
 
-    /// Returns a new `CheetahString` with all characters converted to lowercase.
+    /// Splits by a pattern, skipping a trailing empty substring.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("HELLO");
-    /// assert_eq!(s.to_lowercase(), "hello");
+    /// let s = CheetahString::from("a;b;");
+    /// let parts: Vec<&str> = s.split_terminator(';').collect();
+    /// assert_eq!(parts, vec!["a", "b"]);
     /// ```
     #[inline]
-    pub fn to_lowercase(&self) -> CheetahString {
-        CheetahString::from_string(self.as_str().to_lowercase())
+    pub fn split_terminator<P: StrPattern>(&self, pat: P) -> SplitTerminator<'_, P> {
+        SplitTerminator::new(self.as_str(), pat)
     }
 
-    /// Replaces all occurrences of a pattern with another string.
+    /// Splits by a pattern from the end, skipping a trailing empty substring.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("hello world");
-    /// assert_eq!(s.replace("world", "rust"), "hello rust");
+    /// let s = CheetahString::from("a;b;");
+    /// let parts: Vec<&str> = s.rsplit_terminator(';').collect();
+    /// assert_eq!(parts, vec!["b", "a"]);
     /// ```
     #[inline]
-    pub fn replace<P: AsRef<str>>(&self, from: P, to: &str) -> CheetahString {
-        CheetahString::from_string(self.as_str().replace(from.as_ref(), to))
+    pub fn rsplit_terminator<P: StrPattern>(&self, pat: P) -> RSplitTerminator<'_, P> {
+        RSplitTerminator::new(self.as_str(), pat)
     }
 
-    /// Returns a new `CheetahString` with the specified range replaced.
+    /// Splits by a pattern, keeping each separator at the end of its
+    /// substring.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("hello world");
-    /// assert_eq!(s.replacen("l", "L", 1), "heLlo world");
+    /// let s = CheetahString::from("line1\nline2\n");
+    /// let parts: Vec<&str> = s.split_inclusive('\n').collect();
+    /// assert_eq!(parts, vec!["line1\n", "line2\n"]);
     /// ```
     #[inline]
-    pub fn replacen<P: AsRef<str>>(&self, from: P, to: &str, count: usize) -> CheetahString {
-        CheetahString::from_string(self.as_str().replacen(from.as_ref(), to, count))
+    pub fn split_inclusive<P: StrPattern>(&self, pat: P) -> SplitInclusive<'_, P> {
+        SplitInclusive::new(self.as_str(), pat)
     }
 
-    /// Returns a substring as a new `CheetahString`.
+    /// Splits at the first occurrence of a pattern.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// Panics if the range is out of bounds, inverted, or not on valid UTF-8
-    /// character boundaries. Use [`CheetahString::try_substring`] for a
-    /// recoverable error.
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("KEYS=a=b");
+    /// assert_eq!(s.split_once('='), Some(("KEYS", "a=b")));
+    /// assert_eq!(s.split_once(':'), None);
+    /// ```
+    #[inline]
+    pub fn split_once<P: StrPattern>(&self, pat: P) -> Option<(&str, &str)> {
+        let value = self.as_str();
+        let (start, end) = pat.into_matcher().find_in(value)?;
+        Some((&value[..start], &value[end..]))
+    }
+
+    /// Splits at the last occurrence of a pattern.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("hello world");
-    /// assert_eq!(s.substring(0, 5), "hello");
-    /// assert_eq!(s.substring(6, 11), "world");
+    /// let s = CheetahString::from("KEYS=a=b");
+    /// assert_eq!(s.rsplit_once('='), Some(("KEYS=a", "b")));
     /// ```
     #[inline]
-    pub fn substring(&self, start: usize, end: usize) -> CheetahString {
-        self.try_substring(start, end)
-            .expect("substring range must be in bounds and on UTF-8 character boundaries")
+    pub fn rsplit_once<P: StrPattern>(&self, pat: P) -> Option<(&str, &str)> {
+        let value = self.as_str();
+        let (start, end) = pat.into_matcher().rfind_in(value)?;
+        Some((&value[..start], &value[end..]))
     }
 
-    /// Returns a substring as a new `CheetahString`, or a public error when
-    /// the requested range is invalid.
+    /// Returns an iterator over the non-overlapping matches of a pattern.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("hello world");
-    /// assert_eq!(s.try_substring(0, 5).unwrap(), "hello");
-    /// assert!(s.try_substring(0, 20).is_err());
+    /// let s = CheetahString::from("a1b22c333");
+    /// let digits: Vec<&str> = s.matches(char::is_numeric).collect();
+    /// assert_eq!(digits, vec!["1", "2", "2", "3", "3", "3"]);
     /// ```
     #[inline]
-    pub fn try_substring(&self, start: usize, end: usize) -> crate::Result<CheetahString> {
-        let value = self.as_str();
-        let len = value.len();
+    pub fn matches<P: StrPattern>(&self, pat: P) -> Matches<'_, P> {
+        Matches::new(self.as_str(), pat)
+    }
 
-        if start > end {
-            return Err(crate::Error::InvalidRange { start, end });
-        }
+    /// Returns an iterator over the non-overlapping matches of a pattern, in
+    /// reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("abcXXXabcYYYabc");
+    /// assert_eq!(s.rmatches("abc").count(), 3);
+    /// ```
+    #[inline]
+    pub fn rmatches<P: StrPattern>(&self, pat: P) -> RMatches<'_, P> {
+        RMatches::new(self.as_str(), pat)
+    }
 
-        if start > len {
-            return Err(crate::Error::IndexOutOfBounds { index: start, len });
-        }
+    /// Returns an iterator over the matches of a pattern and their byte
+    /// offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("abcXXXabc");
+    /// let found: Vec<(usize, &str)> = s.match_indices("abc").collect();
+    /// assert_eq!(found, vec![(0, "abc"), (6, "abc")]);
+    /// ```
+    #[inline]
+    pub fn match_indices<P: StrPattern>(&self, pat: P) -> MatchIndices<'_, P> {
+        MatchIndices::new(self.as_str(), pat)
+    }
 
-        if end > len {
-            return Err(crate::Error::IndexOutOfBounds { index: end, len });
-        }
+    /// Returns an iterator over the matches of a pattern and their byte
+    /// offsets, in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("abcXXXabc");
+    /// let found: Vec<(usize, &str)> = s.rmatch_indices("abc").collect();
+    /// assert_eq!(found, vec![(6, "abc"), (0, "abc")]);
+    /// ```
+    #[inline]
+    pub fn rmatch_indices<P: StrPattern>(&self, pat: P) -> RMatchIndices<'_, P> {
+        RMatchIndices::new(self.as_str(), pat)
+    }
 
-        if !value.is_char_boundary(start) {
-            return Err(crate::Error::InvalidCharBoundary { index: start });
-        }
+    /// Returns an iterator over the lines of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("line1\nline2\nline3");
+    /// let lines: Vec<&str> = s.lines().collect();
+    /// assert_eq!(lines, vec!["line1", "line2", "line3"]);
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.as_str().lines()
+    }
 
-        if !value.is_char_boundary(end) {
-            return Err(crate::Error::InvalidCharBoundary { index: end });
-        }
+    /// Splits by a pattern, yielding owned [`CheetahString`] pieces.
+    ///
+    /// Pieces that fit the inline capacity are stored inline and longer
+    /// pieces share the parent's backing, so no piece allocates. Reverse iteration follows the
+    /// capability of [`CheetahString::split`] for the same pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("topic-a topic-b");
+    /// let keys: Vec<CheetahString> = s.split_owned(' ').collect();
+    /// assert_eq!(keys, ["topic-a", "topic-b"]);
+    /// ```
+    #[inline]
+    pub fn split_owned<'a, P>(&'a self, pat: P) -> OwnedPieces<'a, P::Iter, N>
+    where
+        P: SplitPattern<'a>,
+    {
+        OwnedPieces::new(self, pat.split_pattern(self.as_str()))
+    }
 
-        Ok(CheetahString::from_slice(&value[start..end]))
+    /// Returns an iterator over the lines of the string as owned
+    /// [`CheetahString`] pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("line1\r\nline2\n");
+    /// let lines: Vec<CheetahString> = s.lines_owned().collect();
+    /// assert_eq!(lines, ["line1", "line2"]);
+    /// ```
+    #[inline]
+    pub fn lines_owned(&self) -> OwnedPieces<'_, str::Lines<'_>, N> {
+        OwnedPieces::new(self, self.as_str().lines())
     }
 
-    /// Repeats the string `n` times.
+    /// Splits on Unicode whitespace, yielding owned [`CheetahString`] pieces.
     ///
     /// # Examples
     ///
     /// ```
     /// use cheetah_string::CheetahString;
     ///
-    /// let s = CheetahString::from("abc");
-    /// assert_eq!(s.repeat(3), "abcabcabc");
+    /// let s = CheetahString::from("  SET key\tvalue ");
+    /// let words: Vec<CheetahString> = s.split_whitespace_owned().collect();
+    /// assert_eq!(words, ["SET", "key", "value"]);
     /// ```
     #[inline]
-    pub fn repeat(&self, n: usize) -> CheetahString {
-        CheetahString::from_string(self.as_str().repeat(n))
+    pub fn split_whitespace_owned(&self) -> OwnedPieces<'_, str::SplitWhitespace<'_>, N> {
+        OwnedPieces::new(self, self.as_str().split_whitespace())
+    }
+
+    /// Splits at the first occurrence of a pattern into owned
+    /// [`CheetahString`] halves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("Content-Type: text/plain");
+    /// let (name, value) = s.split_once_owned(": ").unwrap();
+    /// assert_eq!(name, "Content-Type");
+    /// assert_eq!(value, "text/plain");
+    /// ```
+    #[inline]
+    pub fn split_once_owned<P: StrPattern>(
+        &self,
+        pat: P,
+    ) -> Option<(CheetahStringN<N>, CheetahStringN<N>)> {
+        let (start, end) = pat.into_matcher().find_in(self.as_str())?;
+        Some((
+            self.slice_unchecked(0, start),
+            self.slice_unchecked(end, self.len()),
+        ))
+    }
+
+    /// Returns the text with leading and trailing whitespace removed as a
+    /// [`CheetahString`].
+    ///
+    /// Like [`CheetahString::substring`], the result is a clone when nothing
+    /// is trimmed, a static sub-slice of a static value, or a window into the
+    /// backing of a shared value; only short results of non-static values
+    /// are copied inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let line = CheetahString::from(format!("  {}\n", "payload-".repeat(8)));
+    /// let payload = line.trimmed();
+    /// assert_eq!(payload, "payload-".repeat(8));
+    /// assert!(payload.ptr_eq(&line));
+    /// ```
+    #[inline]
+    pub fn trimmed(&self) -> Self {
+        let value = self.as_str();
+        let start = value.len() - value.trim_start().len();
+        self.slice_unchecked(start, value.trim_end().len().max(start))
+    }
+
+    /// Returns the text with leading whitespace removed as a
+    /// [`CheetahString`], sharing storage like [`CheetahString::trimmed`].
+    #[inline]
+    pub fn trimmed_start(&self) -> Self {
+        let value = self.as_str();
+        self.slice_unchecked(value.len() - value.trim_start().len(), value.len())
+    }
+
+    /// Returns the text with trailing whitespace removed as a
+    /// [`CheetahString`], sharing storage like [`CheetahString::trimmed`].
+    #[inline]
+    pub fn trimmed_end(&self) -> Self {
+        self.slice_unchecked(0, self.as_str().trim_end().len())
+    }
+
+    /// Returns the text after `prefix` as a [`CheetahString`], or `None` if
+    /// it does not start with a match.
+    ///
+    /// The result shares storage like [`CheetahString::trimmed`], so removing
+    /// a marker from a long value does not copy it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let topic = CheetahString::from_static_str("%RETRY%consumer-group-orders");
+    /// let original = topic.strip_prefix_owned("%RETRY%").unwrap();
+    /// assert_eq!(original, "consumer-group-orders");
+    /// assert_eq!(topic.strip_prefix_owned("%DLQ%"), None);
+    /// ```
+    #[inline]
+    pub fn strip_prefix_owned<P: StrPattern>(&self, prefix: P) -> Option<Self> {
+        let len = prefix.into_matcher().prefix_len(self.as_str())?;
+        Some(self.slice_unchecked(len, self.len()))
+    }
+
+    /// Returns the text before `suffix` as a [`CheetahString`], or `None` if
+    /// it does not end with a match.
+    ///
+    /// The result shares storage like [`CheetahString::trimmed`].
+    #[inline]
+    pub fn strip_suffix_owned<P: StrPattern>(&self, suffix: P) -> Option<Self> {
+        let len = suffix.into_matcher().suffix_len(self.as_str())?;
+        Some(self.slice_unchecked(0, self.len() - len))
+    }
+
+    /// Returns [`CheetahString::trim_matches`] as a [`CheetahString`],
+    /// sharing storage like [`CheetahString::trimmed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("\"quoted\"");
+    /// assert_eq!(s.trim_matches_owned('"'), "quoted");
+    /// ```
+    #[inline]
+    pub fn trim_matches_owned<P: StrPattern>(&self, pat: P) -> Self {
+        let (start, end) = trimmed_range(self.as_str(), pat.into_matcher(), true, true);
+        self.slice_unchecked(start, end)
+    }
+
+    /// Returns [`CheetahString::trim_start_matches`] as a [`CheetahString`],
+    /// sharing storage like [`CheetahString::trimmed`].
+    #[inline]
+    pub fn trim_start_matches_owned<P: StrPattern>(&self, pat: P) -> Self {
+        let (start, end) = trimmed_range(self.as_str(), pat.into_matcher(), true, false);
+        self.slice_unchecked(start, end)
+    }
+
+    /// Returns [`CheetahString::trim_end_matches`] as a [`CheetahString`],
+    /// sharing storage like [`CheetahString::trimmed`].
+    #[inline]
+    pub fn trim_end_matches_owned<P: StrPattern>(&self, pat: P) -> Self {
+        let (start, end) = trimmed_range(self.as_str(), pat.into_matcher(), false, true);
+        self.slice_unchecked(start, end)
+    }
+
+    /// Returns an iterator over the characters of the string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello");
+    /// let chars: Vec<char> = s.chars().collect();
+    /// assert_eq!(chars, vec!['h', 'e', 'l', 'l', 'o']);
+    /// let reversed: Vec<char> = s.chars().rev().collect();
+    /// assert_eq!(reversed, vec!['o', 'l', 'l', 'e', 'h']);
+    /// ```
+    #[inline]
+    pub fn chars(&self) -> str::Chars<'_> {
+        self.as_str().chars()
+    }
+
+    // Transformation methods - create new CheetahString
+
+    /// Returns a new `CheetahString` with all characters converted to uppercase.
+    ///
+    /// When no character changes, the result is a clone that shares the
+    /// backing of `self` and does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello");
+    /// assert_eq!(s.to_uppercase(), "HELLO");
+    /// ```
+    #[inline]
+    pub fn to_uppercase(&self) -> Self {
+        self.to_uppercase_cow().into_owned()
+    }
+
+    /// Converts all characters to uppercase, borrowing `self` when no
+    /// character changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    /// use std::borrow::Cow;
+    ///
+    /// let s = CheetahString::from("ORDERS-TOPIC-PRIMARY-REGION");
+    /// assert!(matches!(s.to_uppercase_cow(), Cow::Borrowed(_)));
+    /// assert!(matches!(s.to_lowercase_cow(), Cow::Owned(_)));
+    /// ```
+    pub fn to_uppercase_cow(&self) -> Cow<'_, Self> {
+        let value = self.as_str();
+        if value
+            .chars()
+            .all(|ch| maps_to_itself(ch, ch.to_uppercase()))
+        {
+            return Cow::Borrowed(self);
+        }
+
+        // Longer inputs use std's vectorised conversion. Only a handful of
+        // case mappings shrink text, so such results are rarely inline-sized.
+        if value.len() > N {
+            return Cow::Owned(Self::freeze_string(value.to_uppercase()));
+        }
+
+        let mut out = Staging::with_capacity(value.len());
+        for ch in value.chars().flat_map(char::to_uppercase) {
+            out.push(ch);
+        }
+        Cow::Owned(out.finish())
+    }
+
+    /// Returns a new `CheetahString` with all characters converted to lowercase.
+    ///
+    /// When no character changes, the result is a clone that shares the
+    /// backing of `self` and does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("HELLO");
+    /// assert_eq!(s.to_lowercase(), "hello");
+    /// ```
+    #[inline]
+    pub fn to_lowercase(&self) -> Self {
+        self.to_lowercase_cow().into_owned()
+    }
+
+    /// Converts all characters to lowercase, borrowing `self` when no
+    /// character changes.
+    pub fn to_lowercase_cow(&self) -> Cow<'_, Self> {
+        let value = self.as_str();
+        if value
+            .chars()
+            .all(|ch| maps_to_itself(ch, ch.to_lowercase()))
+        {
+            return Cow::Borrowed(self);
+        }
+
+        // A capital sigma lowercases by context, which only std implements.
+        if value.len() > N || value.contains('\u{3a3}') {
+            return Cow::Owned(Self::freeze_string(value.to_lowercase()));
+        }
+
+        let mut out = Staging::with_capacity(value.len());
+        for ch in value.chars().flat_map(char::to_lowercase) {
+            out.push(ch);
+        }
+        Cow::Owned(out.finish())
+    }
+
+    /// Returns a copy with ASCII letters converted to uppercase; other
+    /// characters are unchanged.
+    ///
+    /// Returns a clone of `self` without allocating when there are no
+    /// lowercase ASCII letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("x-trace-é");
+    /// assert_eq!(s.to_ascii_uppercase(), "X-TRACE-é");
+    /// ```
+    #[inline]
+    pub fn to_ascii_uppercase(&self) -> Self {
+        self.to_ascii_uppercase_cow().into_owned()
+    }
+
+    /// Converts ASCII letters to uppercase, borrowing `self` when there are
+    /// no lowercase ASCII letters.
+    pub fn to_ascii_uppercase_cow(&self) -> Cow<'_, Self> {
+        if !self.as_bytes().iter().any(u8::is_ascii_lowercase) {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(self.convert_ascii_case(true))
+    }
+
+    /// Returns a copy with ASCII letters converted to lowercase; other
+    /// characters are unchanged.
+    ///
+    /// Returns a clone of `self` without allocating when there are no
+    /// uppercase ASCII letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("Content-Type");
+    /// assert_eq!(s.to_ascii_lowercase(), "content-type");
+    /// ```
+    #[inline]
+    pub fn to_ascii_lowercase(&self) -> Self {
+        self.to_ascii_lowercase_cow().into_owned()
+    }
+
+    /// Converts ASCII letters to lowercase, borrowing `self` when there are
+    /// no uppercase ASCII letters.
+    pub fn to_ascii_lowercase_cow(&self) -> Cow<'_, Self> {
+        if !self.as_bytes().iter().any(u8::is_ascii_uppercase) {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(self.convert_ascii_case(false))
+    }
+
+    /// Converts ASCII letters to uppercase in place.
+    ///
+    /// Inline values are converted in place. Other values are replaced by a
+    /// converted copy only if a letter changes, so shared backings are never
+    /// written to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let mut s = CheetahString::from("get");
+    /// s.make_ascii_uppercase();
+    /// assert_eq!(s, "GET");
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        match &mut self.inner {
+            InnerString::Inline(inline) => inline.make_ascii_uppercase(),
+            _ => {
+                if self.as_bytes().iter().any(u8::is_ascii_lowercase) {
+                    *self = self.convert_ascii_case(true);
+                }
+            }
+        }
+    }
+
+    /// Converts ASCII letters to lowercase in place.
+    ///
+    /// Like [`CheetahString::make_ascii_uppercase`], only inline values are
+    /// modified directly.
+    pub fn make_ascii_lowercase(&mut self) {
+        match &mut self.inner {
+            InnerString::Inline(inline) => inline.make_ascii_lowercase(),
+            _ => {
+                if self.as_bytes().iter().any(u8::is_ascii_uppercase) {
+                    *self = self.convert_ascii_case(false);
+                }
+            }
+        }
+    }
+
+    /// Copies the text with its ASCII letters converted to one case.
+    fn convert_ascii_case(&self, upper: bool) -> Self {
+        let value = self.as_str();
+        if let Some(mut inline) = InlineStr::from_str(value) {
+            if upper {
+                inline.make_ascii_uppercase();
+            } else {
+                inline.make_ascii_lowercase();
+            }
+            return Self {
+                inner: InnerString::Inline(inline),
+            };
+        }
+
+        let mut text = String::from(value);
+        if upper {
+            text.make_ascii_uppercase();
+        } else {
+            text.make_ascii_lowercase();
+        }
+        Self::freeze_string(text)
+    }
+
+    /// Replaces all occurrences of a pattern with another string.
+    ///
+    /// When nothing is replaced, the result is a clone that shares the
+    /// backing of `self` and does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello world");
+    /// assert_eq!(s.replace("world", "rust"), "hello rust");
+    /// ```
+    #[inline]
+    pub fn replace<P: AsRef<str>>(&self, from: P, to: &str) -> Self {
+        self.replace_matches(from.as_ref(), to, usize::MAX)
+            .into_owned()
+    }
+
+    /// Replaces all occurrences of a pattern, borrowing `self` when nothing
+    /// changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    /// use std::borrow::Cow;
+    ///
+    /// let topic = CheetahString::from("orders.primary.region-a");
+    /// assert!(matches!(topic.replace_cow("%RETRY%", ""), Cow::Borrowed(_)));
+    /// assert_eq!(topic.replace_cow(".", "/").as_str(), "orders/primary/region-a");
+    /// ```
+    #[inline]
+    pub fn replace_cow<P: AsRef<str>>(&self, from: P, to: &str) -> Cow<'_, Self> {
+        self.replace_matches(from.as_ref(), to, usize::MAX)
+    }
+
+    /// Returns a new `CheetahString` with the specified range replaced.
+    ///
+    /// When nothing is replaced, the result is a clone of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello world");
+    /// assert_eq!(s.replacen("l", "L", 1), "heLlo world");
+    /// ```
+    #[inline]
+    pub fn replacen<P: AsRef<str>>(&self, from: P, to: &str, count: usize) -> Self {
+        self.replace_matches(from.as_ref(), to, count).into_owned()
+    }
+
+    /// Replaces the first `count` occurrences of a pattern, borrowing `self`
+    /// when nothing changes.
+    #[inline]
+    pub fn replacen_cow<P: AsRef<str>>(&self, from: P, to: &str, count: usize) -> Cow<'_, Self> {
+        self.replace_matches(from.as_ref(), to, count)
+    }
+
+    /// Shared body of [`CheetahString::replace`] and
+    /// [`CheetahString::replacen`], mirroring the std implementation.
+    fn replace_matches(&self, from: &str, to: &str, count: usize) -> Cow<'_, Self> {
+        let value = self.as_str();
+        let mut matches = value.match_indices(from).take(count).peekable();
+        if from == to || matches.peek().is_none() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut out = Staging::with_capacity(0);
+        let mut last_end = 0;
+        for (start, part) in matches {
+            out.push_str(&value[last_end..start]);
+            out.push_str(to);
+            last_end = start + part.len();
+        }
+        out.push_str(&value[last_end..]);
+        Cow::Owned(out.finish())
+    }
+
+    /// Returns a substring as a new `CheetahString`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, inverted, or not on valid UTF-8
+    /// character boundaries. Use [`CheetahString::try_substring`] for a
+    /// recoverable error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello world");
+    /// assert_eq!(s.substring(0, 5), "hello");
+    /// assert_eq!(s.substring(6, 11), "world");
+    /// ```
+    #[inline]
+    pub fn substring(&self, start: usize, end: usize) -> Self {
+        self.try_substring(start, end)
+            .expect("substring range must be in bounds and on UTF-8 character boundaries")
+    }
+
+    /// Returns a substring as a new `CheetahString`, or a public error when
+    /// the requested range is invalid.
+    ///
+    /// Substrings of long shared values reuse the parent's backing
+    /// allocation, so slicing is O(1) and allocation-free. Use
+    /// [`CheetahString::detach`] to release a large parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello world");
+    /// assert_eq!(s.try_substring(0, 5).unwrap(), "hello");
+    /// assert!(s.try_substring(0, 20).is_err());
+    /// ```
+    #[inline]
+    pub fn try_substring(&self, start: usize, end: usize) -> crate::Result<CheetahStringN<N>> {
+        self.check_range(start, end)?;
+        Ok(self.slice_unchecked(start, end))
+    }
+
+    /// Returns the given byte range as a new `CheetahString`.
+    ///
+    /// This is the range-based form of [`CheetahString::substring`], useful
+    /// for prefix (`..end`) and suffix (`start..`) views.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, inverted, or not on valid UTF-8
+    /// character boundaries. Use [`CheetahString::try_slice`] for a
+    /// recoverable error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello world");
+    /// assert_eq!(s.slice(..5), "hello");
+    /// assert_eq!(s.slice(6..), "world");
+    /// ```
+    #[inline]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        self.try_slice(range)
+            .expect("slice range must be in bounds and on UTF-8 character boundaries")
+    }
+
+    /// Returns the given byte range as a new `CheetahString`, or a public
+    /// error when the requested range is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello world");
+    /// assert_eq!(s.try_slice(6..).unwrap(), "world");
+    /// assert!(s.try_slice(..20).is_err());
+    /// ```
+    #[inline]
+    pub fn try_slice<R: RangeBounds<usize>>(&self, range: R) -> crate::Result<CheetahStringN<N>> {
+        let (start, end) = crate::range::resolve(&range, self.len())?;
+        self.try_substring(start, end)
+    }
+
+    /// Divides the string into two values at a byte index.
+    ///
+    /// Both halves reuse the parent's backing when they are too long to be
+    /// stored inline.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is out of bounds or not on a UTF-8 character
+    /// boundary. Use [`CheetahString::try_split_at`] for a recoverable error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("orders@group-a");
+    /// let (topic, group) = s.split_at(6);
+    /// assert_eq!(topic, "orders");
+    /// assert_eq!(group, "@group-a");
+    /// ```
+    #[inline]
+    pub fn split_at(&self, mid: usize) -> (CheetahStringN<N>, CheetahStringN<N>) {
+        self.try_split_at(mid)
+            .expect("split index must be in bounds and on a UTF-8 character boundary")
+    }
+
+    /// Divides the string into two values at a byte index, or returns a
+    /// public error when `mid` is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("hello");
+    /// assert!(s.try_split_at(2).is_ok());
+    /// assert!(s.try_split_at(6).is_err());
+    /// ```
+    #[inline]
+    pub fn try_split_at(
+        &self,
+        mid: usize,
+    ) -> crate::Result<(CheetahStringN<N>, CheetahStringN<N>)> {
+        let len = self.len();
+        self.check_range(mid, len)?;
+        Ok((self.slice_unchecked(0, mid), self.slice_unchecked(mid, len)))
+    }
+
+    #[inline]
+    fn check_range(&self, start: usize, end: usize) -> crate::Result<()> {
+        crate::range::check(self.as_str(), start, end)
+    }
+
+    /// Repeats the string `n` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let s = CheetahString::from("abc");
+    /// assert_eq!(s.repeat(3), "abcabcabc");
+    /// ```
+    #[inline]
+    pub fn repeat(&self, n: usize) -> Self {
+        let value = self.as_str();
+        let len = value.len().checked_mul(n).expect("capacity overflow");
+        if len == 0 {
+            return Self::empty();
+        }
+        if n == 1 {
+            return self.clone();
+        }
+
+        Self::from_pieces(core::iter::repeat(value).take(n))
+    }
+}
+
+/// Returns whether `mapped`, the case mapping of `ch`, is `ch` itself.
+#[inline]
+fn maps_to_itself(ch: char, mut mapped: impl Iterator<Item = char>) -> bool {
+    mapped.next() == Some(ch) && mapped.next().is_none()
+}
+
+/// Returns the byte range of `value` left after repeatedly removing matches
+/// at the start and/or end.
+///
+/// Empty matches stop the loop, so an empty pattern trims nothing.
+fn trimmed_range<M: Matcher>(
+    value: &str,
+    mut matcher: M,
+    start: bool,
+    end: bool,
+) -> (usize, usize) {
+    let (mut from, mut to) = (0, value.len());
+    if start {
+        while let Some(len @ 1..) = matcher.prefix_len(&value[from..to]) {
+            from += len;
+        }
+    }
+    if end {
+        while let Some(len @ 1..) = matcher.suffix_len(&value[from..to]) {
+            to -= len;
+        }
     }
+    (from, to)
 }
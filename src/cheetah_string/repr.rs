@@ -1,5 +1,6 @@
-use alloc::sync::Arc;
+use core::{slice, str};
 
+use super::shared::SharedStr;
 use crate::inline::InlineStr;
 pub(super) use crate::inline::INLINE_CAPACITY;
 
@@ -9,17 +10,99 @@ pub(super) use crate::inline::INLINE_CAPACITY;
 ///
 /// Variants:
 ///
-/// * `Inline` - Inline storage for strings <= 23 bytes (zero heap allocations).
+/// * `Inline` - Inline storage for strings <= `N` bytes (zero heap allocations).
 /// * `Static(&'static str)` - A static string slice (zero heap allocations).
-/// * `Shared(Arc<str>)` - A reference-counted string slice (single heap allocation, optimized).
+/// * `Shared(SharedStr)` - A thin reference-counted buffer (single heap allocation).
+/// * `Sliced(SharedSlice)` - A window into a `Shared` allocation (zero heap allocations).
+///
+/// With the default `N = 23` the enum is 24 bytes on both 64-bit and 32-bit
+/// targets.
+/// `InlineStr` keeps its length byte last and every other variant fits in the
+/// first 16 bytes, so the invalid length values serve as the discriminant and
+/// `Option<CheetahString>` needs no extra space.
 #[derive(Clone)]
-pub(super) enum InnerString {
-    /// Inline storage for short strings (up to 23 bytes).
+pub(super) enum InnerString<const N: usize> {
+    /// Inline storage for short strings (up to `N` bytes).
     /// Stores the length and data directly without heap allocation.
-    Inline(InlineStr),
+    Inline(InlineStr<N>),
     /// Static string slice with 'static lifetime.
     Static(&'static str),
-    /// Reference-counted string slice (single heap allocation).
-    /// Preferred for long immutable strings created from owned or borrowed data.
-    Shared(Arc<str>),
+    /// Thin reference-counted buffer (single heap allocation).
+    /// Holds long text copied from owned or borrowed data, or an adopted
+    /// `bytes::Bytes` handle whose payload is never copied.
+    Shared(SharedStr),
+    /// Substring view that keeps the parent backing alive.
+    /// Produced by slicing a `Shared` value; never created for inline-sized
+    /// text.
+    Sliced(SharedSlice),
+}
+
+/// Byte window into a shared backing allocation.
+///
+/// Offsets are stored as `u32` so the variant fits beside the inline length
+/// byte without growing `CheetahString`. Windows that cannot be described
+/// this way fall back to copying.
+#[derive(Clone)]
+pub(super) struct SharedSlice {
+    backing: SharedStr,
+    start: u32,
+    len: u32,
+}
+
+impl SharedSlice {
+    /// Creates a window over `backing[start..end]`.
+    ///
+    /// Returns `None` when the offsets do not fit the compact encoding. The
+    /// caller must have validated that both offsets are in bounds and on
+    /// UTF-8 character boundaries.
+    #[inline]
+    pub(super) fn new(backing: SharedStr, start: usize, end: usize) -> Option<Self> {
+        debug_assert!(start <= end && end <= backing.len());
+        debug_assert!(
+            backing.as_str().is_char_boundary(start) && backing.as_str().is_char_boundary(end)
+        );
+
+        let start_u32 = u32::try_from(start).ok()?;
+        let len = u32::try_from(end - start).ok()?;
+        Some(Self {
+            backing,
+            start: start_u32,
+            len,
+        })
+    }
+
+    #[inline]
+    pub(super) const fn as_str(&self) -> &str {
+        let backing = self.backing.as_str();
+        // SAFETY: `new` only accepts in-bounds offsets on character boundaries
+        // of the immutable backing allocation.
+        unsafe {
+            let data = backing.as_ptr().add(self.start as usize);
+            str::from_utf8_unchecked(slice::from_raw_parts(data, self.len as usize))
+        }
+    }
+
+    #[inline]
+    pub(super) const fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    #[inline]
+    pub(super) fn start(&self) -> usize {
+        self.start as usize
+    }
+
+    #[inline]
+    pub(super) fn backing(&self) -> &SharedStr {
+        &self.backing
+    }
+}
+
+/// Lets a shared window own a `bytes::Bytes` handle without copying.
+#[cfg(feature = "bytes")]
+impl AsRef<[u8]> for SharedSlice {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
 }
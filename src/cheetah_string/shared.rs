@@ -0,0 +1,361 @@
+//! Thin reference-counted backing for long `CheetahString` values.
+//!
+//! A [`SharedStr`] is a single pointer to a heap block that starts with a
+//! [`Header`] (reference count and length). The UTF-8 text follows the header
+//! in the same allocation, or, for values adopted from `bytes::Bytes`, the
+//! block stores the `Bytes` handle instead of a copy of its payload.
+//!
+//! Keeping the length in the header is what lets `CheetahString` fit in 24
+//! bytes: the pointer is one word, unlike the two-word `Arc<str>`.
+
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::fmt;
+use core::ptr::{self, NonNull};
+use core::slice;
+use core::str;
+use core::sync::atomic::{self, AtomicUsize, Ordering};
+
+/// Set in `Header::len` when the block stores a `bytes::Bytes` handle instead
+/// of inline text. Text lengths never exceed `isize::MAX`, so the bit is free.
+#[cfg(feature = "bytes")]
+const EXTERNAL: usize = 1 << (usize::BITS - 1);
+
+/// Reference counts above this value abort, mirroring `Arc`.
+const MAX_REFCOUNT: usize = isize::MAX as usize;
+
+/// Offset of inline text within a block. `u8` needs no padding after the
+/// header, so this is the header size for every length.
+const TEXT_OFFSET: usize = core::mem::size_of::<Header>();
+
+#[repr(C)]
+struct Header {
+    count: AtomicUsize,
+    len: usize,
+}
+
+/// Contents of a block that adopts a `bytes::Bytes` handle. `data` caches
+/// the payload pointer so reading the text needs no call into `bytes`.
+#[cfg(feature = "bytes")]
+#[repr(C)]
+struct External {
+    data: *const u8,
+    bytes: bytes::Bytes,
+}
+
+// `External` must start right after the header, where `as_str` looks for it.
+#[cfg(feature = "bytes")]
+const _: () = assert!(core::mem::align_of::<External>() <= core::mem::align_of::<Header>());
+
+/// Single-word, reference-counted, immutable UTF-8 buffer.
+pub(super) struct SharedStr {
+    ptr: NonNull<Header>,
+}
+
+// SAFETY: The text is immutable after construction and the reference count is
+// atomic, exactly like `Arc<str>`. The optional `bytes::Bytes` owner is itself
+// `Send + Sync`.
+unsafe impl Send for SharedStr {}
+// SAFETY: See the `Send` impl above.
+unsafe impl Sync for SharedStr {}
+
+impl SharedStr {
+    /// Copies `value` into a new block with a reference count of one.
+    pub(super) fn from_str(value: &str) -> Self {
+        let len = value.len();
+        let (layout, offset) = text_layout(len);
+        let ptr = allocate(layout, len);
+
+        // SAFETY: `allocate` returned a block of `layout`, which has room for
+        // `len` bytes at `offset`, and `value` cannot overlap a fresh block.
+        unsafe {
+            ptr::copy_nonoverlapping(value.as_ptr(), ptr.as_ptr().cast::<u8>().add(offset), len);
+        }
+
+        Self { ptr }
+    }
+
+    /// Allocates a block for exactly `len` bytes and lets `fill` write its
+    /// text.
+    ///
+    /// Returns `None` when `fill` fails or does not write exactly `len`
+    /// bytes, which can happen when a `Display` impl or a cloned iterator is
+    /// not deterministic.
+    pub(super) fn try_fill<F>(len: usize, fill: F) -> Option<Self>
+    where
+        F: FnOnce(&mut BlockWriter) -> fmt::Result,
+    {
+        let (layout, offset) = text_layout(len);
+        let ptr = allocate(layout, len);
+        // Dropping `block` frees the allocation without reading its text, so
+        // an early return is fine while the text is incomplete.
+        let block = Self { ptr };
+
+        let mut writer = BlockWriter {
+            // SAFETY: the block has room for `len` bytes at `offset`.
+            dst: unsafe { ptr.as_ptr().cast::<u8>().add(offset) },
+            remaining: len,
+        };
+        if fill(&mut writer).is_err() || writer.remaining != 0 {
+            return None;
+        }
+
+        Some(block)
+    }
+
+    /// Stores a `bytes::Bytes` handle in a new block without copying its
+    /// payload.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must contain valid UTF-8.
+    #[cfg(feature = "bytes")]
+    pub(super) unsafe fn from_utf8_bytes_unchecked(bytes: bytes::Bytes) -> Self {
+        let (layout, offset) = external_layout();
+        let ptr = allocate(layout, bytes.len() | EXTERNAL);
+
+        // SAFETY: the block has room for one `External` at `offset`, which is
+        // suitably aligned by `Layout::extend`.
+        unsafe {
+            ptr.as_ptr()
+                .cast::<u8>()
+                .add(offset)
+                .cast::<External>()
+                .write(External {
+                    data: bytes.as_ptr(),
+                    bytes,
+                });
+        }
+
+        Self { ptr }
+    }
+
+    #[inline]
+    fn header(&self) -> &Header {
+        // SAFETY: `ptr` points to a live header while any handle exists.
+        unsafe { self.ptr.as_ref() }
+    }
+
+    /// Reads `Header::len` without creating a reference to the header, which
+    /// `const fn` does not allow for types with interior mutability.
+    #[inline]
+    const fn raw_len(&self) -> usize {
+        // SAFETY: `ptr` points to a live header while any handle exists, and
+        // `len` is never written after allocation.
+        unsafe { (*self.ptr.as_ptr().cast_const()).len }
+    }
+
+    #[inline]
+    pub(super) const fn len(&self) -> usize {
+        #[cfg(feature = "bytes")]
+        {
+            self.raw_len() & !EXTERNAL
+        }
+        #[cfg(not(feature = "bytes"))]
+        {
+            self.raw_len()
+        }
+    }
+
+    #[inline]
+    pub(super) const fn as_str(&self) -> &str {
+        let len = self.len();
+        // SAFETY: text blocks hold `len` initialized UTF-8 bytes at
+        // `TEXT_OFFSET`; external blocks store an `External` there whose
+        // `data` points at the validated payload that its `Bytes` keeps
+        // alive. Neither is mutated while the block is shared.
+        unsafe {
+            let block = self.ptr.as_ptr().cast_const().cast::<u8>().add(TEXT_OFFSET);
+            #[cfg(feature = "bytes")]
+            let data = if self.raw_len() & EXTERNAL != 0 {
+                (*block.cast::<External>()).data
+            } else {
+                block
+            };
+            #[cfg(not(feature = "bytes"))]
+            let data = block;
+            str::from_utf8_unchecked(slice::from_raw_parts(data, len))
+        }
+    }
+
+    /// Returns the adopted `bytes::Bytes` handle, if this block stores one.
+    #[cfg(feature = "bytes")]
+    #[inline]
+    pub(super) fn external(&self) -> Option<&bytes::Bytes> {
+        if self.header().len & EXTERNAL == 0 {
+            return None;
+        }
+
+        let (_, offset) = external_layout();
+        // SAFETY: the `EXTERNAL` flag is only set by
+        // `from_utf8_bytes_unchecked`, which wrote an `External` at `offset`.
+        let external = unsafe {
+            &*self
+                .ptr
+                .as_ptr()
+                .cast::<u8>()
+                .add(offset)
+                .cast::<External>()
+        };
+        Some(&external.bytes)
+    }
+
+    /// Returns the number of handles that share this block.
+    #[inline]
+    pub(super) fn strong_count(&self) -> usize {
+        self.header().count.load(Ordering::Acquire)
+    }
+
+    /// Returns the size of the block plus, for adopted `Bytes`, the length of
+    /// the payload it keeps alive.
+    #[inline]
+    pub(super) fn heap_bytes(&self) -> usize {
+        #[cfg(feature = "bytes")]
+        if let Some(bytes) = self.external() {
+            return external_layout().0.size() + bytes.len();
+        }
+        text_layout(self.len()).0.size()
+    }
+
+    /// Returns whether both handles point at the same block.
+    #[inline]
+    pub(super) fn ptr_eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+
+    /// Returns the address of the block, which identifies it among live
+    /// blocks.
+    #[inline]
+    pub(super) fn as_block_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr().cast_const().cast::<u8>()
+    }
+}
+
+/// Copies text into the uninitialized text area of a fresh block, refusing
+/// to write past its end.
+pub(super) struct BlockWriter {
+    dst: *mut u8,
+    remaining: usize,
+}
+
+impl fmt::Write for BlockWriter {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        if value.len() > self.remaining {
+            return Err(fmt::Error);
+        }
+
+        // SAFETY: `dst` has room for `remaining` more bytes of the block, and
+        // `value` cannot overlap the fresh allocation. Only whole `str`
+        // pieces are copied, so a full block holds valid UTF-8.
+        unsafe {
+            ptr::copy_nonoverlapping(value.as_ptr(), self.dst, value.len());
+            self.dst = self.dst.add(value.len());
+        }
+        self.remaining -= value.len();
+        Ok(())
+    }
+}
+
+impl Clone for SharedStr {
+    #[inline]
+    fn clone(&self) -> Self {
+        // Relaxed is sufficient: a new handle can only be created from an
+        // existing one, as with `Arc::clone`.
+        let old = self.header().count.fetch_add(1, Ordering::Relaxed);
+        if old > MAX_REFCOUNT {
+            refcount_overflow();
+        }
+
+        Self { ptr: self.ptr }
+    }
+}
+
+impl Drop for SharedStr {
+    fn drop(&mut self) {
+        if self.header().count.fetch_sub(1, Ordering::Release) != 1 {
+            return;
+        }
+        // Synchronize with every earlier release so no other handle still
+        // reads the text when the block is freed.
+        atomic::fence(Ordering::Acquire);
+
+        #[cfg(feature = "bytes")]
+        if self.header().len & EXTERNAL != 0 {
+            let (layout, offset) = external_layout();
+            // SAFETY: this was the last handle, so the `External` written by
+            // `from_utf8_bytes_unchecked` is dropped exactly once before the
+            // block of `layout` is returned to the allocator.
+            unsafe {
+                let raw = self.ptr.as_ptr().cast::<u8>();
+                ptr::drop_in_place(raw.add(offset).cast::<External>());
+                dealloc(raw, layout);
+            }
+            return;
+        }
+
+        let (layout, _) = text_layout(self.len());
+        // SAFETY: this was the last handle and the block was allocated with
+        // the layout recomputed from its unchanged length.
+        unsafe { dealloc(self.ptr.as_ptr().cast::<u8>(), layout) }
+    }
+}
+
+impl AsRef<[u8]> for SharedStr {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+/// Allocates a block of `layout` and initializes its header.
+fn allocate(layout: Layout, len: usize) -> NonNull<Header> {
+    // SAFETY: `layout` always includes the non-zero-sized header.
+    let raw = unsafe { alloc(layout) };
+    let Some(ptr) = NonNull::new(raw.cast::<Header>()) else {
+        handle_alloc_error(layout);
+    };
+    crate::metrics::record_allocation(layout.size());
+
+    // SAFETY: the block is freshly allocated and large enough for a header.
+    unsafe {
+        ptr.as_ptr().write(Header {
+            count: AtomicUsize::new(1),
+            len,
+        });
+    }
+
+    ptr
+}
+
+/// Layout of a block holding `len` bytes of text, and the text offset.
+#[inline]
+fn text_layout(len: usize) -> (Layout, usize) {
+    let text = Layout::array::<u8>(len).expect("capacity overflow");
+    let (layout, offset) = Layout::new::<Header>()
+        .extend(text)
+        .expect("capacity overflow");
+    debug_assert_eq!(offset, TEXT_OFFSET);
+    (layout.pad_to_align(), offset)
+}
+
+/// Layout of a block holding a `bytes::Bytes` handle, and its offset.
+#[cfg(feature = "bytes")]
+#[inline]
+fn external_layout() -> (Layout, usize) {
+    let (layout, offset) = Layout::new::<Header>()
+        .extend(Layout::new::<External>())
+        .expect("header layout fits");
+    debug_assert_eq!(offset, TEXT_OFFSET);
+    (layout.pad_to_align(), offset)
+}
+
+#[cold]
+fn refcount_overflow() -> ! {
+    #[cfg(feature = "std")]
+    {
+        std::process::abort()
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        panic!("CheetahString reference count overflow")
+    }
+}
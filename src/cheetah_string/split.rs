@@ -0,0 +1,576 @@
+//! Pattern-driven split and match iterators.
+//!
+//! These mirror the standard library's `str` iterators of the same names and
+//! yield identical items. Reverse iteration is only available for character
+//! patterns, whose forward and reverse matches always agree.
+
+use core::iter::FusedIterator;
+
+use super::pattern::{DoubleEndedMatcher, Matcher, StrPattern};
+use super::repr::INLINE_CAPACITY;
+use super::CheetahStringN;
+
+/// Match cursor shared by the split and match iterators.
+///
+/// Forward matches start at or after `search_fw` and reverse matches end at
+/// or before `search_bw`, so the two directions never report the same match.
+struct MatchCursor<'a, M> {
+    haystack: &'a str,
+    matcher: M,
+    search_fw: usize,
+    search_bw: usize,
+    exhausted: bool,
+}
+
+impl<'a, M: Matcher> MatchCursor<'a, M> {
+    #[inline]
+    fn new(haystack: &'a str, matcher: M) -> Self {
+        Self {
+            haystack,
+            matcher,
+            search_fw: 0,
+            search_bw: haystack.len(),
+            exhausted: false,
+        }
+    }
+
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        if self.exhausted || self.search_fw > self.search_bw {
+            return None;
+        }
+
+        let offset = self.search_fw;
+        let Some((start, end)) = self
+            .matcher
+            .find_in(&self.haystack[offset..self.search_bw])
+            .map(|(start, end)| (start + offset, end + offset))
+        else {
+            self.exhausted = true;
+            return None;
+        };
+
+        if start == end {
+            // An empty match must not be reported twice; resume after the
+            // next character, or stop at the end of the haystack.
+            match self.haystack[end..].chars().next() {
+                Some(ch) => self.search_fw = end + ch.len_utf8(),
+                None => self.exhausted = true,
+            }
+        } else {
+            self.search_fw = end;
+        }
+
+        Some((start, end))
+    }
+
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        if self.exhausted || self.search_fw > self.search_bw {
+            return None;
+        }
+
+        let offset = self.search_fw;
+        let Some((start, end)) = self
+            .matcher
+            .rfind_in(&self.haystack[offset..self.search_bw])
+            .map(|(start, end)| (start + offset, end + offset))
+        else {
+            self.exhausted = true;
+            return None;
+        };
+
+        if start == end {
+            match self.haystack[..start].chars().next_back() {
+                Some(ch) => self.search_bw = start - ch.len_utf8(),
+                None => self.exhausted = true,
+            }
+        } else {
+            self.search_bw = start;
+        }
+
+        Some((start, end))
+    }
+}
+
+/// Shared state of the split family, following `core::str::SplitInternal`.
+struct SplitInternal<'a, M> {
+    cursor: MatchCursor<'a, M>,
+    start: usize,
+    end: usize,
+    allow_trailing_empty: bool,
+    finished: bool,
+}
+
+impl<'a, M: Matcher> SplitInternal<'a, M> {
+    #[inline]
+    fn new(haystack: &'a str, matcher: M, allow_trailing_empty: bool) -> Self {
+        Self {
+            cursor: MatchCursor::new(haystack, matcher),
+            start: 0,
+            end: haystack.len(),
+            allow_trailing_empty,
+            finished: false,
+        }
+    }
+
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.cursor.haystack
+    }
+
+    #[inline]
+    fn get_end(&mut self) -> Option<&'a str> {
+        if !self.finished {
+            self.finished = true;
+
+            if self.allow_trailing_empty || self.end > self.start {
+                return Some(&self.haystack()[self.start..self.end]);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+
+        match self.cursor.next_match() {
+            Some((start, end)) => {
+                let piece = &self.haystack()[self.start..start];
+                self.start = end;
+                Some(piece)
+            }
+            None => self.get_end(),
+        }
+    }
+
+    #[inline]
+    fn next_inclusive(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+
+        match self.cursor.next_match() {
+            Some((_, end)) => {
+                let piece = &self.haystack()[self.start..end];
+                self.start = end;
+                Some(piece)
+            }
+            None => self.get_end(),
+        }
+    }
+
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.allow_trailing_empty {
+            self.allow_trailing_empty = true;
+            match self.next_back() {
+                Some(piece) if !piece.is_empty() => return Some(piece),
+                _ => {
+                    if self.finished {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        match self.cursor.next_match_back() {
+            Some((start, end)) => {
+                let piece = &self.haystack()[end..self.end];
+                self.end = start;
+                Some(piece)
+            }
+            None => {
+                self.finished = true;
+                Some(&self.haystack()[self.start..self.end])
+            }
+        }
+    }
+
+    fn next_back_inclusive(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+
+        if !self.allow_trailing_empty {
+            self.allow_trailing_empty = true;
+            match self.next_back_inclusive() {
+                Some(piece) if !piece.is_empty() => return Some(piece),
+                _ => {
+                    if self.finished {
+                        return None;
+                    }
+                }
+            }
+        }
+
+        match self.cursor.next_match_back() {
+            Some((_, end)) => {
+                let piece = &self.haystack()[end..self.end];
+                self.end = end;
+                Some(piece)
+            }
+            None => {
+                self.finished = true;
+                Some(&self.haystack()[self.start..self.end])
+            }
+        }
+    }
+
+    #[inline]
+    fn remainder(&self) -> Option<&'a str> {
+        if self.finished {
+            return None;
+        }
+
+        Some(&self.haystack()[self.start..self.end])
+    }
+}
+
+/// Iterator over substrings separated by a pattern.
+///
+/// Returned by [`CheetahString::split`](super::CheetahString::split) for
+/// patterns other than `char` and `&str`.
+pub struct Split<'a, P: StrPattern>(SplitInternal<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> Split<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(SplitInternal::new(haystack, pat.into_matcher(), true))
+    }
+
+    /// Returns the part of the haystack that has not been yielded yet.
+    #[inline]
+    pub fn remainder(&self) -> Option<&'a str> {
+        self.0.remainder()
+    }
+}
+
+/// Iterator over substrings separated by a pattern, in reverse order.
+///
+/// Returned by [`CheetahString::rsplit`](super::CheetahString::rsplit).
+pub struct RSplit<'a, P: StrPattern>(SplitInternal<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> RSplit<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(SplitInternal::new(haystack, pat.into_matcher(), true))
+    }
+}
+
+/// Iterator over pattern-terminated substrings.
+///
+/// Returned by
+/// [`CheetahString::split_terminator`](super::CheetahString::split_terminator).
+pub struct SplitTerminator<'a, P: StrPattern>(SplitInternal<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> SplitTerminator<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(SplitInternal::new(haystack, pat.into_matcher(), false))
+    }
+}
+
+/// Iterator over pattern-terminated substrings, in reverse order.
+///
+/// Returned by
+/// [`CheetahString::rsplit_terminator`](super::CheetahString::rsplit_terminator).
+pub struct RSplitTerminator<'a, P: StrPattern>(SplitInternal<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> RSplitTerminator<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(SplitInternal::new(haystack, pat.into_matcher(), false))
+    }
+}
+
+/// Iterator over substrings that each end with their matched separator.
+///
+/// Returned by
+/// [`CheetahString::split_inclusive`](super::CheetahString::split_inclusive).
+pub struct SplitInclusive<'a, P: StrPattern>(SplitInternal<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> SplitInclusive<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(SplitInternal::new(haystack, pat.into_matcher(), false))
+    }
+}
+
+/// Iterator over at most `n` substrings separated by a pattern.
+///
+/// Returned by [`CheetahString::splitn`](super::CheetahString::splitn).
+pub struct SplitN<'a, P: StrPattern> {
+    inner: SplitInternal<'a, P::Matcher>,
+    count: usize,
+}
+
+impl<'a, P: StrPattern> SplitN<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, count: usize, pat: P) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, pat.into_matcher(), true),
+            count,
+        }
+    }
+}
+
+/// Iterator over at most `n` substrings separated by a pattern, in reverse
+/// order.
+///
+/// Returned by [`CheetahString::rsplitn`](super::CheetahString::rsplitn).
+pub struct RSplitN<'a, P: StrPattern> {
+    inner: SplitInternal<'a, P::Matcher>,
+    count: usize,
+}
+
+impl<'a, P: StrPattern> RSplitN<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, count: usize, pat: P) -> Self {
+        Self {
+            inner: SplitInternal::new(haystack, pat.into_matcher(), true),
+            count,
+        }
+    }
+}
+
+/// Iterator over the matches of a pattern.
+///
+/// Returned by [`CheetahString::matches`](super::CheetahString::matches).
+pub struct Matches<'a, P: StrPattern>(MatchCursor<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> Matches<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(MatchCursor::new(haystack, pat.into_matcher()))
+    }
+}
+
+/// Iterator over the matches of a pattern, in reverse order.
+///
+/// Returned by [`CheetahString::rmatches`](super::CheetahString::rmatches).
+pub struct RMatches<'a, P: StrPattern>(MatchCursor<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> RMatches<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(MatchCursor::new(haystack, pat.into_matcher()))
+    }
+}
+
+/// Iterator over the matches of a pattern and their byte offsets.
+///
+/// Returned by
+/// [`CheetahString::match_indices`](super::CheetahString::match_indices).
+pub struct MatchIndices<'a, P: StrPattern>(MatchCursor<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> MatchIndices<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(MatchCursor::new(haystack, pat.into_matcher()))
+    }
+}
+
+/// Iterator over the matches of a pattern and their byte offsets, in
+/// reverse order.
+///
+/// Returned by
+/// [`CheetahString::rmatch_indices`](super::CheetahString::rmatch_indices).
+pub struct RMatchIndices<'a, P: StrPattern>(MatchCursor<'a, P::Matcher>);
+
+impl<'a, P: StrPattern> RMatchIndices<'a, P> {
+    #[inline]
+    pub(super) fn new(haystack: &'a str, pat: P) -> Self {
+        Self(MatchCursor::new(haystack, pat.into_matcher()))
+    }
+}
+
+macro_rules! split_iterator {
+    ($name:ident, $next:ident, $next_back:ident) => {
+        impl<'a, P: StrPattern> Iterator for $name<'a, P> {
+            type Item = &'a str;
+
+            #[inline]
+            fn next(&mut self) -> Option<&'a str> {
+                self.0.$next()
+            }
+        }
+
+        impl<'a, P: StrPattern> DoubleEndedIterator for $name<'a, P>
+        where
+            P::Matcher: DoubleEndedMatcher,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<&'a str> {
+                self.0.$next_back()
+            }
+        }
+
+        impl<P: StrPattern> FusedIterator for $name<'_, P> {}
+    };
+}
+
+split_iterator!(Split, next, next_back);
+split_iterator!(RSplit, next_back, next);
+split_iterator!(SplitTerminator, next, next_back);
+split_iterator!(RSplitTerminator, next_back, next);
+split_iterator!(SplitInclusive, next_inclusive, next_back_inclusive);
+
+impl<'a, P: StrPattern> Iterator for SplitN<'a, P> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next()
+            }
+        }
+    }
+}
+
+impl<P: StrPattern> FusedIterator for SplitN<'_, P> {}
+
+impl<'a, P: StrPattern> Iterator for RSplitN<'a, P> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        match self.count {
+            0 => None,
+            1 => {
+                self.count = 0;
+                self.inner.get_end()
+            }
+            _ => {
+                self.count -= 1;
+                self.inner.next_back()
+            }
+        }
+    }
+}
+
+impl<P: StrPattern> FusedIterator for RSplitN<'_, P> {}
+
+macro_rules! match_iterator {
+    ($name:ident, $item:ty, $map:expr, $next:ident, $next_back:ident) => {
+        impl<'a, P: StrPattern> Iterator for $name<'a, P> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<$item> {
+                let haystack = self.0.haystack;
+                self.0
+                    .$next()
+                    .map(|(start, end)| $map(start, &haystack[start..end]))
+            }
+        }
+
+        impl<'a, P: StrPattern> DoubleEndedIterator for $name<'a, P>
+        where
+            P::Matcher: DoubleEndedMatcher,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<$item> {
+                let haystack = self.0.haystack;
+                self.0
+                    .$next_back()
+                    .map(|(start, end)| $map(start, &haystack[start..end]))
+            }
+        }
+
+        impl<P: StrPattern> FusedIterator for $name<'_, P> {}
+    };
+}
+
+match_iterator!(
+    Matches,
+    &'a str,
+    |_, piece| piece,
+    next_match,
+    next_match_back
+);
+match_iterator!(
+    RMatches,
+    &'a str,
+    |_, piece| piece,
+    next_match_back,
+    next_match
+);
+match_iterator!(
+    MatchIndices,
+    (usize, &'a str),
+    |start, piece| (start, piece),
+    next_match,
+    next_match_back
+);
+match_iterator!(
+    RMatchIndices,
+    (usize, &'a str),
+    |start, piece| (start, piece),
+    next_match_back,
+    next_match
+);
+
+/// Adapter that turns the borrowed pieces of a parent value into owned
+/// [`CheetahStringN`](super::CheetahStringN)s.
+///
+/// Pieces that fit the inline capacity are stored inline; longer pieces of static or
+/// shared parents reuse the parent's backing, so tokenizing does not allocate
+/// per piece. Reverse iteration is available when the underlying iterator
+/// supports it.
+///
+/// Returned by [`CheetahString::split_owned`](super::CheetahString::split_owned),
+/// [`CheetahString::lines_owned`](super::CheetahString::lines_owned), and
+/// [`CheetahString::split_whitespace_owned`](super::CheetahString::split_whitespace_owned).
+pub struct OwnedPieces<'a, I, const N: usize = INLINE_CAPACITY> {
+    parent: &'a CheetahStringN<N>,
+    inner: I,
+}
+
+impl<'a, I, const N: usize> OwnedPieces<'a, I, N> {
+    #[inline]
+    pub(super) fn new(parent: &'a CheetahStringN<N>, inner: I) -> Self {
+        Self { parent, inner }
+    }
+}
+
+impl<'a, I: Iterator<Item = &'a str>, const N: usize> Iterator for OwnedPieces<'a, I, N> {
+    type Item = CheetahStringN<N>;
+
+    #[inline]
+    fn next(&mut self) -> Option<CheetahStringN<N>> {
+        self.inner
+            .next()
+            .map(|piece| self.parent.owned_piece(piece))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I: DoubleEndedIterator<Item = &'a str>, const N: usize> DoubleEndedIterator
+    for OwnedPieces<'a, I, N>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<CheetahStringN<N>> {
+        self.inner
+            .next_back()
+            .map(|piece| self.parent.owned_piece(piece))
+    }
+}
+
+impl<'a, I: FusedIterator<Item = &'a str>, const N: usize> FusedIterator for OwnedPieces<'a, I, N> {}
@@ -0,0 +1,210 @@
+use alloc::string::String;
+use core::fmt;
+
+use super::repr::InnerString;
+use super::CheetahStringN;
+use crate::inline::InlineStr;
+
+/// Growable text buffer that stays inline until it outgrows `N` bytes.
+///
+/// Backs [`crate::CheetahBuilder`] and the value-producing operations on
+/// `CheetahString`. Text is written straight into inline storage and only
+/// moves to a heap `String` once it no longer fits, so results that fit
+/// inline never touch the allocator.
+#[derive(Clone)]
+pub(crate) enum Staging<const N: usize> {
+    Inline(InlineStr<N>),
+    Heap(String),
+}
+
+impl<const N: usize> Staging<N> {
+    /// Creates a buffer for a result of about `capacity` bytes.
+    ///
+    /// Capacities that fit inline start inline; larger ones reserve the heap
+    /// buffer up front.
+    #[inline]
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        if capacity <= N {
+            Self::Inline(InlineStr::empty())
+        } else {
+            Self::Heap(String::with_capacity(capacity))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Self::Inline(inline) => inline.as_str(),
+            Self::Heap(heap) => heap.as_str(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Inline(inline) => inline.len(),
+            Self::Heap(heap) => heap.len(),
+        }
+    }
+
+    /// Returns `N` while inline, otherwise the heap buffer's capacity.
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        match self {
+            Self::Inline(_) => N,
+            Self::Heap(heap) => heap.capacity(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push_str(&mut self, value: &str) {
+        match self {
+            Self::Inline(inline) => {
+                if !inline.try_push_str(value) {
+                    self.spill(value.len()).push_str(value);
+                }
+            }
+            Self::Heap(heap) => heap.push_str(value),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, ch: char) {
+        match self {
+            Self::Inline(inline) => {
+                if !inline.try_push(ch) {
+                    self.spill(ch.len_utf8()).push(ch);
+                }
+            }
+            Self::Heap(heap) => heap.push(ch),
+        }
+    }
+
+    /// Reserves room for `additional` more bytes, spilling to the heap only
+    /// when they would not fit inline.
+    #[inline]
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        match self {
+            Self::Inline(inline) => {
+                if additional > N - inline.len() {
+                    self.spill(additional);
+                }
+            }
+            Self::Heap(heap) => heap.reserve(additional),
+        }
+    }
+
+    /// Removes all text, keeping any heap capacity.
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        match self {
+            Self::Inline(inline) => *inline = InlineStr::empty(),
+            Self::Heap(heap) => heap.clear(),
+        }
+    }
+
+    /// Replaces bytes `start..end`, which must be on character boundaries,
+    /// with `value`.
+    pub(crate) fn replace_range(&mut self, start: usize, end: usize, value: &str) {
+        match self {
+            Self::Inline(inline) => {
+                if !inline.try_replace_range(start, end, value) {
+                    self.spill(value.len()).replace_range(start..end, value);
+                }
+            }
+            Self::Heap(heap) => heap.replace_range(start..end, value),
+        }
+    }
+
+    pub(crate) fn retain<F: FnMut(char) -> bool>(&mut self, mut keep: F) {
+        match self {
+            Self::Inline(inline) => {
+                let mut kept = InlineStr::empty();
+                for ch in inline.as_str().chars().filter(|&ch| keep(ch)) {
+                    let fits = kept.try_push(ch);
+                    debug_assert!(fits);
+                }
+                *inline = kept;
+            }
+            Self::Heap(heap) => heap.retain(keep),
+        }
+    }
+
+    /// Splits off the text after byte `at`, which must be a character
+    /// boundary. A tail that fits inline does not allocate.
+    pub(crate) fn split_off(&mut self, at: usize) -> Self {
+        let tail = match InlineStr::from_str(&self.as_str()[at..]) {
+            Some(tail) => Self::Inline(tail),
+            None => match self {
+                Self::Heap(heap) => return Self::Heap(heap.split_off(at)),
+                Self::Inline(_) => unreachable!("inline text always fits inline"),
+            },
+        };
+        self.replace_range(at, self.len(), "");
+        tail
+    }
+
+    /// Appends a copy of bytes `start..end`, which must be on character
+    /// boundaries.
+    pub(crate) fn extend_from_within(&mut self, start: usize, end: usize) {
+        match self {
+            Self::Inline(inline) => {
+                let source = *inline;
+                self.push_str(&source.as_str()[start..end]);
+            }
+            Self::Heap(heap) => {
+                assert!(heap.is_char_boundary(start) && heap.is_char_boundary(end));
+                // SAFETY: the copied bytes are a whole-character slice of the
+                // existing text, so the result stays valid UTF-8.
+                unsafe { heap.as_mut_vec().extend_from_within(start..end) }
+            }
+        }
+    }
+
+    /// Moves inline contents to the heap, reserving room for `additional`
+    /// more bytes.
+    #[cold]
+    fn spill(&mut self, additional: usize) -> &mut String {
+        if let Self::Inline(inline) = self {
+            let len = inline.len();
+            let capacity = len
+                .checked_add(additional)
+                .expect("capacity overflow")
+                .max(2 * N);
+            let mut heap = String::with_capacity(capacity);
+            heap.push_str(inline.as_str());
+            *self = Self::Heap(heap);
+        }
+
+        match self {
+            Self::Heap(heap) => heap,
+            Self::Inline(_) => unreachable!("inline contents were just spilled"),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn finish(self) -> CheetahStringN<N> {
+        match self {
+            Self::Inline(inline) => CheetahStringN {
+                inner: InnerString::Inline(inline),
+            },
+            Self::Heap(heap) => CheetahStringN::freeze_string(heap),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn into_string(self) -> String {
+        match self {
+            Self::Inline(inline) => inline.into_string(),
+            Self::Heap(heap) => heap,
+        }
+    }
+}
+
+impl<const N: usize> fmt::Write for Staging<N> {
+    #[inline]
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.push_str(value);
+        Ok(())
+    }
+}
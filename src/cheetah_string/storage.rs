@@ -0,0 +1,178 @@
+use super::repr::InnerString;
+use super::shared::SharedStr;
+use super::CheetahStringN;
+use crate::metrics::{self, Constructor};
+#[cfg(doc)]
+use crate::CheetahString;
+
+/// How a [`CheetahString`] stores its text.
+///
+/// Returned by [`CheetahString::storage_kind`]. The representation is an
+/// implementation detail that may gain variants, so match with a wildcard
+/// arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageKind {
+    /// Text stored in the value itself.
+    Inline,
+    /// A borrowed `&'static str`.
+    Static,
+    /// A reference-counted heap block, possibly adopted from `bytes::Bytes`.
+    Shared,
+    /// A window into another value's shared block, which it keeps alive.
+    Sliced,
+}
+
+impl<const N: usize> CheetahStringN<N> {
+    /// Returns how this value stores its text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::{CheetahString, StorageKind};
+    ///
+    /// let long = CheetahString::from("x".repeat(64));
+    /// assert_eq!(CheetahString::from("short").storage_kind(), StorageKind::Inline);
+    /// assert_eq!(long.storage_kind(), StorageKind::Shared);
+    /// assert_eq!(long.substring(0, 40).storage_kind(), StorageKind::Sliced);
+    /// ```
+    #[inline]
+    pub const fn storage_kind(&self) -> StorageKind {
+        match &self.inner {
+            InnerString::Inline(_) => StorageKind::Inline,
+            InnerString::Static(_) => StorageKind::Static,
+            InnerString::Shared(_) => StorageKind::Shared,
+            InnerString::Sliced(_) => StorageKind::Sliced,
+        }
+    }
+
+    /// Returns whether the text is stored in the value itself.
+    #[inline]
+    pub const fn is_inline(&self) -> bool {
+        matches!(self.inner, InnerString::Inline(_))
+    }
+
+    /// Returns whether the value borrows a `&'static str`.
+    #[inline]
+    pub const fn is_static(&self) -> bool {
+        matches!(self.inner, InnerString::Static(_))
+    }
+
+    /// Returns the borrowed `&'static str` of a static value.
+    ///
+    /// Inline and shared values return `None` even when their text equals a
+    /// static string.
+    #[inline]
+    pub const fn as_static_str(&self) -> Option<&'static str> {
+        match self.inner {
+            InnerString::Static(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns whether both values use the same storage.
+    ///
+    /// Shared and sliced values compare equal when they keep the same heap
+    /// block alive, even if they view different parts of it. Static values
+    /// compare their borrowed slices by address and length. An inline value
+    /// only shares storage with itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let frame = CheetahString::from("frame-".repeat(16));
+    /// let header = frame.substring(0, 30);
+    /// assert!(frame.ptr_eq(&header));
+    /// assert!(!frame.ptr_eq(&CheetahString::from(frame.as_str())));
+    /// ```
+    #[inline]
+    pub fn ptr_eq<const M: usize>(&self, other: &CheetahStringN<M>) -> bool {
+        match (&self.inner, &other.inner) {
+            (InnerString::Inline(_), InnerString::Inline(_)) => self.as_ptr() == other.as_ptr(),
+            (InnerString::Static(a), InnerString::Static(b)) => {
+                a.as_ptr() == b.as_ptr() && a.len() == b.len()
+            }
+            _ => match (self.backing(), other.backing()) {
+                (Some(a), Some(b)) => a.ptr_eq(b),
+                _ => false,
+            },
+        }
+    }
+
+    /// Returns the number of values sharing this value's heap block.
+    ///
+    /// Sliced values count toward their parent's block. Inline and static
+    /// values have no block and return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let topic = CheetahString::from("topic-".repeat(8));
+    /// let copy = topic.clone();
+    /// assert_eq!(topic.shared_count(), Some(2));
+    /// drop(copy);
+    /// assert_eq!(topic.shared_count(), Some(1));
+    /// assert_eq!(CheetahString::from("inline").shared_count(), None);
+    /// ```
+    #[inline]
+    pub fn shared_count(&self) -> Option<usize> {
+        self.backing().map(|backing| backing.strong_count())
+    }
+
+    /// Returns the number of heap bytes this value keeps alive.
+    ///
+    /// This is the size of the shared block, including its header, which all
+    /// values counted by [`CheetahString::shared_count`] keep alive together.
+    /// A sliced value reports its parent's whole block. Inline and static
+    /// values return `0`.
+    ///
+    /// For text adopted from `bytes::Bytes`, only the adopted window is
+    /// included. The value still keeps the whole `Bytes` owner alive, such as
+    /// the network frame the window was sliced from, but that memory is not
+    /// reported; [`CheetahString::detach`] copies the text out of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let frame = CheetahString::from("x".repeat(4096));
+    /// let view = frame.substring(0, 32);
+    /// assert!(view.heap_bytes() > 4096);
+    /// assert!(view.detach().heap_bytes() < 64);
+    /// assert_eq!(CheetahString::from("inline").heap_bytes(), 0);
+    /// ```
+    #[inline]
+    pub fn heap_bytes(&self) -> usize {
+        self.backing().map_or(0, |backing| backing.heap_bytes())
+    }
+
+    /// Returns the address and [`CheetahString::heap_bytes`] of the heap
+    /// block this value keeps alive.
+    #[inline]
+    pub(crate) fn heap_block(&self) -> Option<(*const u8, usize)> {
+        self.backing()
+            .map(|backing| (backing.as_block_ptr(), backing.heap_bytes()))
+    }
+
+    /// Returns the heap block behind a shared or sliced value.
+    #[inline]
+    fn backing(&self) -> Option<&SharedStr> {
+        match &self.inner {
+            InnerString::Shared(s) => Some(s),
+            InnerString::Sliced(s) => Some(s.backing()),
+            InnerString::Inline(_) | InnerString::Static(_) => None,
+        }
+    }
+
+    /// Records this value with the `metrics` counters and returns it.
+    #[inline]
+    pub(crate) fn counted(self, constructor: Constructor) -> Self {
+        metrics::record_value(constructor, self.storage_kind());
+        self
+    }
+}
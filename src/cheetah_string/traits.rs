@@ -4,13 +4,19 @@ use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt::{self, Display};
 use core::hash::{Hash, Hasher};
-use core::ops::Add;
+use core::ops::{Add, AddAssign};
 
-use super::CheetahString;
+use super::CheetahStringN;
 
-impl PartialEq for CheetahString {
+/// Values compare by text, so different inline capacities compare directly.
+impl<const N: usize, const M: usize> PartialEq<CheetahStringN<M>> for CheetahStringN<N> {
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &CheetahStringN<M>) -> bool {
+        // Values resolved through the same registry or sharing a backing
+        // view the same bytes and skip the comparison.
+        if self.as_ptr() == other.as_ptr() && self.len() == other.len() {
+            return true;
+        }
         #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
         {
             crate::simd::eq_bytes(self.as_bytes(), other.as_bytes())
@@ -22,7 +28,7 @@ impl PartialEq for CheetahString {
     }
 }
 
-impl PartialEq<str> for CheetahString {
+impl<const N: usize> PartialEq<str> for CheetahStringN<N> {
     #[inline]
     fn eq(&self, other: &str) -> bool {
         #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
@@ -36,7 +42,7 @@ impl PartialEq<str> for CheetahString {
     }
 }
 
-impl PartialEq<String> for CheetahString {
+impl<const N: usize> PartialEq<String> for CheetahStringN<N> {
     #[inline]
     fn eq(&self, other: &String) -> bool {
         #[cfg(all(feature = "experimental-simd", target_arch = "x86_64"))]
@@ -50,79 +56,105 @@ impl PartialEq<String> for CheetahString {
     }
 }
 
-impl PartialEq<Vec<u8>> for CheetahString {
+impl<const N: usize> PartialEq<Vec<u8>> for CheetahStringN<N> {
     #[inline]
     fn eq(&self, other: &Vec<u8>) -> bool {
         self.as_bytes() == other.as_slice()
     }
 }
 
-impl<'a> PartialEq<&'a str> for CheetahString {
+impl<'a, const N: usize> PartialEq<&'a str> for CheetahStringN<N> {
     #[inline]
     fn eq(&self, other: &&'a str) -> bool {
         self.as_str() == *other
     }
 }
 
-impl PartialEq<CheetahString> for str {
+impl<const N: usize> PartialEq<CheetahStringN<N>> for str {
     #[inline]
-    fn eq(&self, other: &CheetahString) -> bool {
+    fn eq(&self, other: &CheetahStringN<N>) -> bool {
         self == other.as_str()
     }
 }
 
-impl PartialEq<CheetahString> for String {
+impl<const N: usize> PartialEq<CheetahStringN<N>> for String {
     #[inline]
-    fn eq(&self, other: &CheetahString) -> bool {
+    fn eq(&self, other: &CheetahStringN<N>) -> bool {
         self.as_str() == other.as_str()
     }
 }
 
-impl PartialEq<CheetahString> for &str {
+impl<const N: usize> PartialEq<CheetahStringN<N>> for &str {
     #[inline]
-    fn eq(&self, other: &CheetahString) -> bool {
+    fn eq(&self, other: &CheetahStringN<N>) -> bool {
         *self == other.as_str()
     }
 }
 
-impl Eq for CheetahString {}
+impl<const N: usize> Eq for CheetahStringN<N> {}
 
-impl PartialOrd for CheetahString {
+impl<const N: usize, const M: usize> PartialOrd<CheetahStringN<M>> for CheetahStringN<N> {
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn partial_cmp(&self, other: &CheetahStringN<M>) -> Option<Ordering> {
+        Some(self.as_str().cmp(other.as_str()))
     }
 }
 
-impl Ord for CheetahString {
+impl<const N: usize> Ord for CheetahStringN<N> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
         self.as_str().cmp(other.as_str())
     }
 }
 
-impl Hash for CheetahString {
+impl<const N: usize> Hash for CheetahStringN<N> {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
     }
 }
 
-impl Display for CheetahString {
+impl<const N: usize> Display for CheetahStringN<N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
 
-impl fmt::Debug for CheetahString {
+/// Formats like the underlying `str`. The alternate form (`{:#?}`) also shows
+/// the storage representation:
+///
+/// ```
+/// use cheetah_string::CheetahString;
+///
+/// let value = CheetahString::from_static_str("orders");
+/// assert_eq!(format!("{value:?}"), "\"orders\"");
+/// assert_eq!(
+///     format!("{value:#?}"),
+///     "CheetahString {\n    storage: Static,\n    len: 6,\n    value: \"orders\",\n}",
+/// );
+/// ```
+impl<const N: usize> fmt::Debug for CheetahStringN<N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(self.as_str(), f)
+        if !f.alternate() {
+            return fmt::Debug::fmt(self.as_str(), f);
+        }
+
+        let mut debug = f.debug_struct("CheetahString");
+        debug
+            .field("storage", &self.storage_kind())
+            .field("len", &self.len());
+        if let Some(count) = self.shared_count() {
+            debug
+                .field("shared_count", &count)
+                .field("heap_bytes", &self.heap_bytes());
+        }
+        debug.field("value", &self.as_str()).finish()
     }
 }
 
-impl Borrow<str> for CheetahString {
+impl<const N: usize> Borrow<str> for CheetahStringN<N> {
     #[inline]
     fn borrow(&self) -> &str {
         self.as_str()
@@ -131,8 +163,8 @@ impl Borrow<str> for CheetahString {
 
 // Add trait implementations for string concatenation
 
-impl Add<&str> for CheetahString {
-    type Output = CheetahString;
+impl<const N: usize> Add<&str> for CheetahStringN<N> {
+    type Output = CheetahStringN<N>;
 
     /// Concatenates a `CheetahString` with a string slice.
     ///
@@ -147,15 +179,16 @@ impl Add<&str> for CheetahString {
     /// ```
     #[inline]
     fn add(self, rhs: &str) -> Self::Output {
-        let mut value = String::with_capacity(self.len() + rhs.len());
-        value.push_str(self.as_str());
-        value.push_str(rhs);
-        CheetahString::from_string(value)
+        if rhs.is_empty() {
+            return self;
+        }
+
+        Self::from_parts(&[self.as_str(), rhs])
     }
 }
 
-impl Add<&CheetahString> for CheetahString {
-    type Output = CheetahString;
+impl<const N: usize, const M: usize> Add<&CheetahStringN<M>> for CheetahStringN<N> {
+    type Output = CheetahStringN<N>;
 
     /// Concatenates two `CheetahString` values.
     ///
@@ -170,13 +203,13 @@ impl Add<&CheetahString> for CheetahString {
     /// assert_eq!(result, "Hello World");
     /// ```
     #[inline]
-    fn add(self, rhs: &CheetahString) -> Self::Output {
+    fn add(self, rhs: &CheetahStringN<M>) -> Self::Output {
         self + rhs.as_str()
     }
 }
 
-impl Add<String> for CheetahString {
-    type Output = CheetahString;
+impl<const N: usize> Add<String> for CheetahStringN<N> {
+    type Output = CheetahStringN<N>;
 
     /// Concatenates a `CheetahString` with a `String`.
     ///
@@ -192,12 +225,47 @@ impl Add<String> for CheetahString {
     #[inline]
     fn add(self, rhs: String) -> Self::Output {
         if self.is_empty() {
-            return CheetahString::from_string(rhs);
+            return Self::freeze_string(rhs);
         }
 
-        let mut value = String::with_capacity(self.len() + rhs.len());
-        value.push_str(self.as_str());
-        value.push_str(&rhs);
-        CheetahString::from_string(value)
+        self + rhs.as_str()
+    }
+}
+
+impl<const N: usize> AddAssign<&str> for CheetahStringN<N> {
+    /// Appends a string slice in place.
+    ///
+    /// Results that fit inline are written without a heap allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let mut s = CheetahString::from("order");
+    /// s += "@a";
+    /// assert_eq!(s, "order@a");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, rhs: &str) {
+        *self = core::mem::take(self) + rhs;
+    }
+}
+
+impl<const N: usize, const M: usize> AddAssign<&CheetahStringN<M>> for CheetahStringN<N> {
+    /// Appends another `CheetahString` in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::CheetahString;
+    ///
+    /// let mut s = CheetahString::from("Hello");
+    /// s += &CheetahString::from(" World");
+    /// assert_eq!(s, "Hello World");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, rhs: &CheetahStringN<M>) {
+        *self += rhs.as_str();
     }
 }
@@ -2,7 +2,11 @@ use core::fmt;
 use core::str::Utf8Error;
 
 /// Errors that can occur during CheetahString operations
+///
+/// New variants may be added in minor releases, so match with a wildcard
+/// arm.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
     /// UTF-8 validation failed
     Utf8Error(Utf8Error),
@@ -12,6 +16,14 @@ pub enum Error {
     InvalidRange { start: usize, end: usize },
     /// Invalid character boundary
     InvalidCharBoundary { index: usize },
+    /// Number text is malformed at byte `index`, or ends there early
+    InvalidNumber { index: usize },
+    /// Number does not fit the target type; `index` is the digit that
+    /// overflowed
+    NumberOverflow { index: usize },
+    /// Text is neither `true` nor `false`; `index` is the byte at which it
+    /// stops matching both
+    InvalidBool { index: usize },
 }
 
 impl fmt::Display for Error {
@@ -27,6 +39,15 @@ impl fmt::Display for Error {
             Error::InvalidCharBoundary { index } => {
                 write!(f, "index {} is not a char boundary", index)
             }
+            Error::InvalidNumber { index } => {
+                write!(f, "invalid number at byte {}", index)
+            }
+            Error::NumberOverflow { index } => {
+                write!(f, "number overflows at byte {}", index)
+            }
+            Error::InvalidBool { index } => {
+                write!(f, "invalid bool at byte {}", index)
+            }
         }
     }
 }
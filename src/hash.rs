@@ -0,0 +1,50 @@
+//! Word-at-a-time hashing shared by `CheetahKey` fingerprints and the
+//! `known_strings!` perfect hash.
+
+/// Multiplier applied when mixing each word.
+pub(crate) const MULTIPLIER: u64 = 0xBF58_476D_1CE4_E5B9;
+
+/// Second multiplier of the finalizer.
+pub(crate) const FINAL_MULTIPLIER: u64 = 0x94D0_49BB_1331_11EB;
+
+/// Mixes `bytes` into `hash` eight bytes at a time and finalizes the result.
+///
+/// Words are read little-endian and the tail is zero-padded, so the result
+/// is the same on every platform and in const evaluation. Callers fold the
+/// length into the initial `hash` to tell zero-padded tails apart.
+#[inline]
+pub(crate) const fn hash_words(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut rest = bytes;
+    while let [a, b, c, d, e, f, g, h, tail @ ..] = rest {
+        hash = mix(hash, u64::from_le_bytes([*a, *b, *c, *d, *e, *f, *g, *h]));
+        rest = tail;
+    }
+
+    if !rest.is_empty() {
+        let mut word = [0u8; 8];
+        let mut i = 0;
+        while i < rest.len() {
+            word[i] = rest[i];
+            i += 1;
+        }
+        hash = mix(hash, u64::from_le_bytes(word));
+    }
+
+    finalize(hash)
+}
+
+/// Mixes one word into `hash`.
+#[inline]
+pub(crate) const fn mix(hash: u64, word: u64) -> u64 {
+    (hash ^ word).wrapping_mul(MULTIPLIER).rotate_left(31)
+}
+
+/// Spreads every input bit across the whole hash.
+#[inline]
+pub(crate) const fn finalize(mut hash: u64) -> u64 {
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(MULTIPLIER);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(FINAL_MULTIPLIER);
+    hash ^ (hash >> 31)
+}
@@ -0,0 +1,364 @@
+//! Heap accounting for structures built from Cheetah values.
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::{size_of, size_of_val};
+
+use crate::{CheetahBuilder, CheetahKey, CheetahStringN};
+
+/// Reports the heap memory owned by a value.
+///
+/// Implementations describe their storage to a [`MemoryTally`], which
+/// charges each shared allocation once no matter how many handles reach
+/// it. Use [`HeapSize::heap_size`] for a single value, or add several
+/// values to one tally so allocations shared between them are deduplicated.
+///
+/// Heap bytes exclude the value itself: a `Vec<CheetahString>` reports its
+/// element buffer plus any backings the elements keep alive, and the
+/// 24-byte elements are part of that buffer. Hash table and B-tree sizes are
+/// estimates derived from their capacity or length.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{CheetahString, HeapSize, MemoryTally};
+///
+/// let topic = CheetahString::from("a-long-topic-name-shared-by-many-queues");
+/// let queues = vec![topic.clone(), topic.clone(), topic];
+///
+/// let mut tally = MemoryTally::new();
+/// tally.add(&queues);
+/// let buffer = queues.capacity() * std::mem::size_of::<CheetahString>();
+/// let block = queues[0].heap_bytes();
+/// assert_eq!(tally.unique_heap_bytes(), buffer + block);
+/// assert_eq!(tally.shared_counted_bytes(), buffer + 3 * block);
+/// assert_eq!(queues.heap_size(), tally.unique_heap_bytes());
+/// ```
+pub trait HeapSize {
+    /// Records the heap storage reachable from `self` in `tally`.
+    ///
+    /// The default records nothing, which is correct for types that own no
+    /// heap memory.
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        let _ = tally;
+    }
+
+    /// Returns the heap bytes reachable from `self`, counting each shared
+    /// allocation once.
+    fn heap_size(&self) -> usize {
+        let mut tally = MemoryTally::new();
+        tally.add(self);
+        tally.unique_heap_bytes()
+    }
+}
+
+/// Accumulates heap usage across values, deduplicating shared allocations by
+/// address.
+///
+/// Three totals are kept:
+///
+/// - [`MemoryTally::unique_heap_bytes`] charges every allocation once and is
+///   the memory actually held;
+/// - [`MemoryTally::shared_counted_bytes`] charges a shared allocation once
+///   per handle that reaches it, as naive per-value accounting would;
+/// - [`MemoryTally::inline_bytes`] counts text stored inside values, which
+///   costs no heap memory of its own.
+///
+/// Addresses are only compared while the tallied values are alive, so a
+/// tally should not outlive the structure it measures.
+#[derive(Default, Clone)]
+pub struct MemoryTally {
+    seen: BTreeSet<usize>,
+    unique_heap_bytes: usize,
+    shared_counted_bytes: usize,
+    inline_bytes: usize,
+}
+
+impl MemoryTally {
+    /// Creates an empty tally.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the heap storage reachable from `value`.
+    #[inline]
+    pub fn add<T: HeapSize + ?Sized>(&mut self, value: &T) -> &mut Self {
+        value.tally_heap(self);
+        self
+    }
+
+    /// Records an allocation owned by exactly one value.
+    #[inline]
+    pub fn record_owned(&mut self, bytes: usize) {
+        self.unique_heap_bytes += bytes;
+        self.shared_counted_bytes += bytes;
+    }
+
+    /// Records a handle to a shared allocation at `ptr` of `bytes` bytes.
+    ///
+    /// Returns `true` the first time `ptr` is seen, so implementations can
+    /// skip walking contents that have already been counted.
+    #[inline]
+    pub fn record_shared(&mut self, ptr: *const u8, bytes: usize) -> bool {
+        self.shared_counted_bytes += bytes;
+        let first = self.seen.insert(ptr as usize);
+        if first {
+            self.unique_heap_bytes += bytes;
+        }
+        first
+    }
+
+    /// Records `bytes` of text stored inside a value.
+    #[inline]
+    pub fn record_inline(&mut self, bytes: usize) {
+        self.inline_bytes += bytes;
+    }
+
+    /// Returns the heap bytes recorded, counting each allocation once.
+    #[inline]
+    pub fn unique_heap_bytes(&self) -> usize {
+        self.unique_heap_bytes
+    }
+
+    /// Returns the heap bytes recorded, counting shared allocations once per
+    /// handle.
+    #[inline]
+    pub fn shared_counted_bytes(&self) -> usize {
+        self.shared_counted_bytes
+    }
+
+    /// Returns the bytes of text stored inline.
+    #[inline]
+    pub fn inline_bytes(&self) -> usize {
+        self.inline_bytes
+    }
+
+    /// Returns the number of distinct shared allocations recorded.
+    #[inline]
+    pub fn shared_allocations(&self) -> usize {
+        self.seen.len()
+    }
+}
+
+impl fmt::Debug for MemoryTally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryTally")
+            .field("unique_heap_bytes", &self.unique_heap_bytes)
+            .field("shared_counted_bytes", &self.shared_counted_bytes)
+            .field("inline_bytes", &self.inline_bytes)
+            .field("shared_allocations", &self.seen.len())
+            .finish()
+    }
+}
+
+/// Shared and sliced values charge their whole backing block once per
+/// tally; inline values record their text as inline bytes.
+impl<const N: usize> HeapSize for CheetahStringN<N> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        if let Some((ptr, bytes)) = self.heap_block() {
+            tally.record_shared(ptr, bytes);
+        } else if self.is_inline() {
+            tally.record_inline(self.len());
+        }
+    }
+}
+
+impl HeapSize for CheetahKey {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        self.as_cheetah_string().tally_heap(tally);
+    }
+}
+
+impl HeapSize for CheetahBuilder {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        match self.heap_capacity() {
+            Some(capacity) => tally.record_owned(capacity),
+            None => tally.record_inline(self.len()),
+        }
+    }
+}
+
+/// `Bytes` does not expose its owner, so the payload is charged by length
+/// and deduplicated by its address: clones of one buffer count once, while
+/// distinct slices of it count separately. Static payloads are charged too.
+#[cfg(feature = "bytes")]
+impl HeapSize for crate::CheetahBytes {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        if !self.is_empty() {
+            tally.record_shared(self.as_bytes().as_ptr(), self.len());
+        }
+    }
+}
+
+impl HeapSize for String {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.capacity());
+    }
+}
+
+impl HeapSize for str {}
+
+/// A reference reports the heap storage of the value it points to, so
+/// `tally.add(&&values)` matches `tally.add(&values)`. Tallying both a value
+/// and a reference to it charges its owned buffers twice.
+impl<T: HeapSize + ?Sized> HeapSize for &T {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        (**self).tally_heap(tally);
+    }
+}
+
+macro_rules! impl_without_heap {
+    ($($ty:ty),*) => {
+        $(impl HeapSize for $ty {})*
+    };
+}
+
+impl_without_heap!(
+    (),
+    bool,
+    char,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    f32,
+    f64
+);
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        if let Some(value) = self {
+            value.tally_heap(tally);
+        }
+    }
+}
+
+impl<A: HeapSize, B: HeapSize> HeapSize for (A, B) {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        self.0.tally_heap(tally);
+        self.1.tally_heap(tally);
+    }
+}
+
+impl<T: HeapSize> HeapSize for [T] {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        self.iter().for_each(|value| value.tally_heap(tally));
+    }
+}
+
+impl<T: HeapSize, const N: usize> HeapSize for [T; N] {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        self.as_slice().tally_heap(tally);
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Box<T> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(size_of_val(&**self));
+        (**self).tally_heap(tally);
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Arc<T> {
+    /// Charges the allocation, including both reference counts, once per
+    /// tally and walks the contents only the first time.
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        let bytes = 2 * size_of::<usize>() + size_of_val(&**self);
+        if tally.record_shared(Arc::as_ptr(self).cast::<u8>(), bytes) {
+            (**self).tally_heap(tally);
+        }
+    }
+}
+
+impl<T: HeapSize + ?Sized> HeapSize for Rc<T> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        let bytes = 2 * size_of::<usize>() + size_of_val(&**self);
+        if tally.record_shared(Rc::as_ptr(self).cast::<u8>(), bytes) {
+            (**self).tally_heap(tally);
+        }
+    }
+}
+
+impl<T: HeapSize> HeapSize for Vec<T> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.capacity() * size_of::<T>());
+        self.as_slice().tally_heap(tally);
+    }
+}
+
+impl<T: HeapSize> HeapSize for VecDeque<T> {
+    #[inline]
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.capacity() * size_of::<T>());
+        self.iter().for_each(|value| value.tally_heap(tally));
+    }
+}
+
+/// Estimated as the entries themselves; node overhead is not included.
+impl<K: HeapSize, V: HeapSize> HeapSize for BTreeMap<K, V> {
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.len() * size_of::<(K, V)>());
+        for (key, value) in self {
+            key.tally_heap(tally);
+            value.tally_heap(tally);
+        }
+    }
+}
+
+/// Estimated as the entries themselves; node overhead is not included.
+impl<T: HeapSize> HeapSize for BTreeSet<T> {
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.len() * size_of::<T>());
+        self.iter().for_each(|value| value.tally_heap(tally));
+    }
+}
+
+/// Estimated as one entry plus one control byte per slot of capacity.
+#[cfg(feature = "std")]
+impl<K: HeapSize, V: HeapSize, S> HeapSize for std::collections::HashMap<K, V, S> {
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.capacity() * (size_of::<(K, V)>() + 1));
+        for (key, value) in self {
+            key.tally_heap(tally);
+            value.tally_heap(tally);
+        }
+    }
+}
+
+/// Estimated as one entry plus one control byte per slot of capacity.
+#[cfg(feature = "std")]
+impl<T: HeapSize, S> HeapSize for std::collections::HashSet<T, S> {
+    fn tally_heap(&self, tally: &mut MemoryTally) {
+        tally.record_owned(self.capacity() * (size_of::<T>() + 1));
+        self.iter().for_each(|value| value.tally_heap(tally));
+    }
+}
@@ -4,53 +4,251 @@ use core::str;
 /// Maximum capacity for inline string storage (23 bytes + 1 byte for length = 24 bytes total).
 pub(crate) const INLINE_CAPACITY: usize = 23;
 
-/// Shared inline storage for short UTF-8 strings.
+/// Largest inline capacity accepted by `CheetahStringN`.
+pub(crate) const MAX_INLINE_CAPACITY: usize = 63;
+
+/// Length byte of [`InlineStr`].
+///
+/// Only `0..=MAX_INLINE_CAPACITY` are valid, so the remaining byte values form
+/// a niche that lets `CheetahString` store its other variants (and `Option`'s
+/// `None`) without a separate discriminant.
+// Variants other than `L0` are only produced by `InlineLen::new`.
+#[allow(dead_code)]
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum InlineLen {
+    L0 = 0,
+    L1,
+    L2,
+    L3,
+    L4,
+    L5,
+    L6,
+    L7,
+    L8,
+    L9,
+    L10,
+    L11,
+    L12,
+    L13,
+    L14,
+    L15,
+    L16,
+    L17,
+    L18,
+    L19,
+    L20,
+    L21,
+    L22,
+    L23,
+    L24,
+    L25,
+    L26,
+    L27,
+    L28,
+    L29,
+    L30,
+    L31,
+    L32,
+    L33,
+    L34,
+    L35,
+    L36,
+    L37,
+    L38,
+    L39,
+    L40,
+    L41,
+    L42,
+    L43,
+    L44,
+    L45,
+    L46,
+    L47,
+    L48,
+    L49,
+    L50,
+    L51,
+    L52,
+    L53,
+    L54,
+    L55,
+    L56,
+    L57,
+    L58,
+    L59,
+    L60,
+    L61,
+    L62,
+    L63,
+}
+
+impl InlineLen {
+    #[inline]
+    const fn new(len: usize) -> Self {
+        assert!(len <= MAX_INLINE_CAPACITY);
+        // SAFETY: `InlineLen` is `repr(u8)` with one variant for every value in
+        // `0..=MAX_INLINE_CAPACITY`, and `len` was checked against that range.
+        unsafe { core::mem::transmute::<u8, InlineLen>(len as u8) }
+    }
+
+    #[inline]
+    const fn get(self) -> usize {
+        self as usize
+    }
+}
+
+/// Shared inline storage for short UTF-8 strings of up to `N` bytes.
+///
+/// The length is stored last so the niche in [`InlineLen`] sits at byte `N`.
+/// For `N = 23` this leaves bytes `0..16` free for the other `CheetahString`
+/// variants.
 #[derive(Clone, Copy)]
-pub(crate) struct InlineStr {
-    len: u8,
-    data: [u8; INLINE_CAPACITY],
+#[repr(C)]
+pub(crate) struct InlineStr<const N: usize> {
+    data: [u8; N],
+    len: InlineLen,
 }
 
-impl InlineStr {
+impl<const N: usize> InlineStr<N> {
+    /// Rejects capacities that the length byte cannot describe.
+    const VALID_CAPACITY: () = assert!(
+        N <= MAX_INLINE_CAPACITY,
+        "inline capacity must be at most 63 bytes"
+    );
+
     #[inline]
     pub(crate) const fn empty() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_CAPACITY;
         Self {
-            len: 0,
-            data: [0; INLINE_CAPACITY],
+            data: [0; N],
+            len: InlineLen::L0,
         }
     }
 
     #[inline]
     pub(crate) fn from_str(value: &str) -> Option<Self> {
-        if value.len() > INLINE_CAPACITY {
+        if value.len() > N {
             return None;
         }
 
         let mut inline = Self::empty();
         inline.data[..value.len()].copy_from_slice(value.as_bytes());
-        inline.len = value.len() as u8;
+        inline.len = InlineLen::new(value.len());
         Some(inline)
     }
 
+    /// Concatenates `parts` in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is longer than `N` bytes, which is a compile
+    /// error when evaluated in a `const` or `static` item.
+    pub(crate) const fn from_parts_const(parts: &[&str]) -> Self {
+        let mut inline = Self::empty();
+        let mut len = 0;
+        let mut part = 0;
+        while part < parts.len() {
+            let bytes = parts[part].as_bytes();
+            assert!(bytes.len() <= N - len, "text exceeds the inline capacity");
+            let mut i = 0;
+            while i < bytes.len() {
+                inline.data[len + i] = bytes[i];
+                i += 1;
+            }
+            len += bytes.len();
+            part += 1;
+        }
+        inline.len = InlineLen::new(len);
+        inline
+    }
+
+    /// Appends `value` if the result still fits in `N` bytes.
+    ///
+    /// Returns `false` and leaves the contents unchanged otherwise.
+    #[inline]
+    pub(crate) fn try_push_str(&mut self, value: &str) -> bool {
+        let len = self.len();
+        let new_len = len + value.len();
+        if new_len > N {
+            return false;
+        }
+
+        self.data[len..new_len].copy_from_slice(value.as_bytes());
+        self.len = InlineLen::new(new_len);
+        true
+    }
+
+    /// Appends `ch` if the result still fits in `N` bytes.
+    #[inline]
+    pub(crate) fn try_push(&mut self, ch: char) -> bool {
+        self.try_push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Replaces bytes `start..end` with `value` if the result still fits in
+    /// `N` bytes.
+    ///
+    /// Returns `false` and leaves the contents unchanged otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start..end` is not an in-bounds range on character
+    /// boundaries.
+    pub(crate) fn try_replace_range(&mut self, start: usize, end: usize, value: &str) -> bool {
+        let text = self.as_str();
+        assert!(
+            start <= end && text.is_char_boundary(start) && text.is_char_boundary(end),
+            "inline edit range must be on UTF-8 character boundaries"
+        );
+
+        let len = text.len();
+        let new_len = len - (end - start) + value.len();
+        if new_len > N {
+            return false;
+        }
+
+        self.data.copy_within(end..len, start + value.len());
+        self.data[start..start + value.len()].copy_from_slice(value.as_bytes());
+        self.len = InlineLen::new(new_len);
+        true
+    }
+
+    /// Converts ASCII letters to uppercase in place.
+    ///
+    /// Only ASCII bytes change, so the contents stay valid UTF-8.
+    #[inline]
+    pub(crate) fn make_ascii_uppercase(&mut self) {
+        let len = self.len();
+        self.data[..len].make_ascii_uppercase();
+    }
+
+    /// Converts ASCII letters to lowercase in place.
+    #[inline]
+    pub(crate) fn make_ascii_lowercase(&mut self) {
+        let len = self.len();
+        self.data[..len].make_ascii_lowercase();
+    }
+
     #[inline]
-    pub(crate) fn as_str(&self) -> &str {
+    pub(crate) const fn as_str(&self) -> &str {
         // SAFETY: InlineStr is only constructed from valid UTF-8 strings.
         unsafe { str::from_utf8_unchecked(self.as_bytes()) }
     }
 
     #[inline]
-    pub(crate) fn as_bytes(&self) -> &[u8] {
-        &self.data[..self.len as usize]
+    pub(crate) const fn as_bytes(&self) -> &[u8] {
+        self.data.split_at(self.len.get()).0
     }
 
     #[inline]
-    pub(crate) fn len(&self) -> usize {
-        self.len as usize
+    pub(crate) const fn len(&self) -> usize {
+        self.len.get()
     }
 
     #[inline]
-    pub(crate) fn is_empty(&self) -> bool {
-        self.len == 0
+    pub(crate) const fn is_empty(&self) -> bool {
+        self.len.get() == 0
     }
 
     #[inline]
@@ -0,0 +1,127 @@
+//! Deduplicating interner for long `CheetahString` values.
+
+use alloc::string::String;
+use std::collections::HashSet;
+use std::sync::{Mutex, MutexGuard, OnceLock, PoisonError};
+
+use crate::inline::INLINE_CAPACITY;
+use crate::CheetahString;
+
+/// Thread-safe table that returns one canonical `CheetahString` per text.
+///
+/// Equal long strings interned through the same table share a single
+/// backing allocation, so thousands of copies of a topic or group name cost
+/// one allocation instead of one each. Values that fit inline (23 bytes or
+/// fewer) are already allocation-free and bypass the table.
+///
+/// Use [`CheetahInterner::global`] for a process-wide table or
+/// [`CheetahInterner::new`] for one scoped to a component. Entries stay alive
+/// until [`CheetahInterner::purge`] drops those no longer referenced outside
+/// the table.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::CheetahInterner;
+///
+/// let interner = CheetahInterner::new();
+/// let a = interner.intern("RMQ_SYS_TRACE_TOPIC_00042");
+/// let b = interner.intern_string(String::from("RMQ_SYS_TRACE_TOPIC_00042"));
+/// assert_eq!(a.as_ptr(), b.as_ptr());
+/// ```
+#[derive(Default)]
+pub struct CheetahInterner {
+    entries: Mutex<HashSet<CheetahString>>,
+}
+
+impl CheetahInterner {
+    /// Creates an empty interner.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the process-wide interner.
+    pub fn global() -> &'static CheetahInterner {
+        static GLOBAL: OnceLock<CheetahInterner> = OnceLock::new();
+        GLOBAL.get_or_init(CheetahInterner::new)
+    }
+
+    /// Returns the canonical value for `value`, copying it only the first
+    /// time it is seen.
+    pub fn intern(&self, value: &str) -> CheetahString {
+        if value.len() <= INLINE_CAPACITY {
+            return CheetahString::from_slice(value);
+        }
+
+        let mut entries = self.lock();
+        if let Some(existing) = entries.get(value) {
+            return existing.clone();
+        }
+
+        let interned = CheetahString::from_slice(value);
+        entries.insert(interned.clone());
+        interned
+    }
+
+    /// Returns the canonical value for `value`, freezing the owned string
+    /// the first time it is seen.
+    pub fn intern_string(&self, value: String) -> CheetahString {
+        if value.len() <= INLINE_CAPACITY {
+            return CheetahString::from_string(value);
+        }
+
+        let mut entries = self.lock();
+        if let Some(existing) = entries.get(value.as_str()) {
+            return existing.clone();
+        }
+
+        let interned = CheetahString::from_string(value);
+        entries.insert(interned.clone());
+        interned
+    }
+
+    /// Returns the canonical value equal to `value` if it has been interned.
+    pub fn get(&self, value: &str) -> Option<CheetahString> {
+        self.lock().get(value).cloned()
+    }
+
+    /// Returns the number of interned entries.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether the interner holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drops entries that are no longer referenced outside the interner and
+    /// returns how many were removed.
+    pub fn purge(&self) -> usize {
+        let mut entries = self.lock();
+        let before = entries.len();
+        entries.retain(|entry| entry.shared_count() != Some(1));
+        before - entries.len()
+    }
+
+    /// Removes every entry. Values handed out earlier remain valid.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    #[inline]
+    fn lock(&self) -> MutexGuard<'_, HashSet<CheetahString>> {
+        // The set is never left in an inconsistent state, so a panic while
+        // another caller held the lock does not invalidate it.
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl core::fmt::Debug for CheetahInterner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CheetahInterner")
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -0,0 +1,384 @@
+//! Prehashed `CheetahString` keys for hot hash-map lookups.
+
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{BuildHasher, Hash, Hasher};
+use core::ops::Deref;
+
+use crate::hash::{finalize, hash_words, mix, MULTIPLIER};
+use crate::CheetahString;
+
+const SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Computes the 64-bit fingerprint stored by [`CheetahKey`].
+///
+/// The function is deterministic across processes and platforms. It is fast
+/// but not keyed, so it offers no protection against adversarially chosen
+/// keys.
+#[inline]
+fn fingerprint(bytes: &[u8]) -> u64 {
+    hash_words(SEED ^ (bytes.len() as u64).wrapping_mul(MULTIPLIER), bytes)
+}
+
+/// A `CheetahString` paired with a fingerprint computed once at construction.
+///
+/// `Hash` writes only the cached fingerprint and `PartialEq` compares
+/// fingerprints before bytes, so repeated lookups of long keys skip both
+/// rehashing and most full comparisons. Pair it with
+/// [`CheetahKeyBuildHasher`] to pass the fingerprint through unchanged.
+///
+/// `CheetahKey` deliberately does not implement `Borrow<str>` or
+/// `Borrow<CheetahString>`: its hash is not the hash of the underlying text.
+/// Look keys up from borrowed text through [`CheetahKeyStr`], which hashes
+/// the same fingerprint.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{CheetahKey, CheetahKeyBuildHasher};
+/// use std::collections::HashMap;
+///
+/// let mut properties = HashMap::with_hasher(CheetahKeyBuildHasher::default());
+/// properties.insert(CheetahKey::from("UNIQ_KEY"), "7F00000100002A9F000000000001");
+///
+/// let lookup = CheetahKey::from_static_str("UNIQ_KEY");
+/// assert_eq!(properties.get(&lookup), Some(&"7F00000100002A9F000000000001"));
+/// ```
+#[derive(Clone)]
+pub struct CheetahKey {
+    hash: u64,
+    value: CheetahString,
+}
+
+impl CheetahKey {
+    /// Wraps `value`, computing its fingerprint once.
+    #[inline]
+    pub fn new(value: CheetahString) -> Self {
+        Self {
+            hash: fingerprint(value.as_bytes()),
+            value,
+        }
+    }
+
+    /// Creates a key from a static string without allocating.
+    #[inline]
+    pub fn from_static_str(value: &'static str) -> Self {
+        Self::new(CheetahString::from_static_str(value))
+    }
+
+    /// Returns the cached 64-bit fingerprint.
+    #[inline]
+    pub fn fingerprint(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn as_cheetah_string(&self) -> &CheetahString {
+        &self.value
+    }
+
+    /// Returns the key text.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    /// Returns the key text as a borrowed key.
+    #[inline]
+    pub fn as_key_str(&self) -> &CheetahKeyStr {
+        CheetahKeyStr::new(self.as_str())
+    }
+
+    /// Unwraps the key, discarding the fingerprint.
+    #[inline]
+    pub fn into_inner(self) -> CheetahString {
+        self.value
+    }
+}
+
+impl PartialEq for CheetahKey {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.value == other.value
+    }
+}
+
+impl Eq for CheetahKey {}
+
+impl PartialEq<str> for CheetahKey {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.value == *other
+    }
+}
+
+impl<'a> PartialEq<&'a str> for CheetahKey {
+    #[inline]
+    fn eq(&self, other: &&'a str) -> bool {
+        self.value == **other
+    }
+}
+
+impl PartialOrd for CheetahKey {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CheetahKey {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl Hash for CheetahKey {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl Deref for CheetahKey {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for CheetahKey {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<CheetahKeyStr> for CheetahKey {
+    #[inline]
+    fn borrow(&self) -> &CheetahKeyStr {
+        self.as_key_str()
+    }
+}
+
+impl fmt::Display for CheetahKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+impl fmt::Debug for CheetahKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.value, f)
+    }
+}
+
+impl From<CheetahString> for CheetahKey {
+    #[inline]
+    fn from(value: CheetahString) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&CheetahString> for CheetahKey {
+    #[inline]
+    fn from(value: &CheetahString) -> Self {
+        Self::new(value.clone())
+    }
+}
+
+impl From<&str> for CheetahKey {
+    #[inline]
+    fn from(value: &str) -> Self {
+        Self::new(CheetahString::from_slice(value))
+    }
+}
+
+impl From<String> for CheetahKey {
+    #[inline]
+    fn from(value: String) -> Self {
+        Self::new(CheetahString::from_string(value))
+    }
+}
+
+impl From<CheetahKey> for CheetahString {
+    #[inline]
+    fn from(key: CheetahKey) -> Self {
+        key.into_inner()
+    }
+}
+
+/// A borrowed `str` that hashes like a [`CheetahKey`].
+///
+/// This is the borrowed form of `CheetahKey`, used to look up keys from a
+/// `&str` or [`CheetahString`] without building a key. Its `Hash` computes
+/// the fingerprint on every call, so prefer a `CheetahKey` for lookups that
+/// repeat.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{CheetahKey, CheetahKeyBuildHasher, CheetahKeyStr, CheetahString};
+/// use std::collections::HashMap;
+///
+/// let mut properties = HashMap::with_hasher(CheetahKeyBuildHasher);
+/// properties.insert(CheetahKey::from("TAGS"), "TagA");
+///
+/// let name = CheetahString::from("TAGS");
+/// assert_eq!(properties.get(CheetahKeyStr::new(&name)), Some(&"TagA"));
+/// ```
+#[repr(transparent)]
+pub struct CheetahKeyStr(str);
+
+impl CheetahKeyStr {
+    /// Views `text` as a borrowed key.
+    #[inline]
+    pub fn new(text: &str) -> &Self {
+        // SAFETY: `Self` is `repr(transparent)` over `str`. The cast keeps
+        // the length metadata and the lifetime of `text`.
+        unsafe { &*(text as *const str as *const Self) }
+    }
+
+    /// Computes the fingerprint a [`CheetahKey`] with this text caches.
+    #[inline]
+    pub fn fingerprint(&self) -> u64 {
+        fingerprint(self.0.as_bytes())
+    }
+
+    /// Returns the key text.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for CheetahKeyStr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for CheetahKeyStr {}
+
+impl PartialOrd for CheetahKeyStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CheetahKeyStr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl Hash for CheetahKeyStr {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.fingerprint());
+    }
+}
+
+impl AsRef<str> for CheetahKeyStr {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for CheetahKeyStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Debug for CheetahKeyStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// `BuildHasher` that passes a [`CheetahKey`] fingerprint through unchanged.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CheetahKeyBuildHasher;
+
+impl BuildHasher for CheetahKeyBuildHasher {
+    type Hasher = CheetahKeyHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        CheetahKeyHasher {
+            hash: 0,
+            written: false,
+        }
+    }
+}
+
+/// Hasher produced by [`CheetahKeyBuildHasher`].
+///
+/// A single `write_u64` call, as issued by `CheetahKey`, becomes the final
+/// hash. Other writes are mixed with the fingerprint function so the hasher
+/// stays usable for arbitrary key types.
+#[derive(Clone, Copy, Debug)]
+pub struct CheetahKeyHasher {
+    hash: u64,
+    written: bool,
+}
+
+impl Hasher for CheetahKeyHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.write_u64(fingerprint(bytes));
+    }
+
+    #[inline]
+    fn write_u64(&mut self, value: u64) {
+        self.hash = if self.written {
+            finalize(mix(self.hash, value))
+        } else {
+            value
+        };
+        self.written = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_depends_on_length_and_tail() {
+        assert_ne!(fingerprint(b""), fingerprint(b"\0"));
+        assert_ne!(fingerprint(b"abcdefgh"), fingerprint(b"abcdefgh\0"));
+        assert_ne!(fingerprint(b"KEYS"), fingerprint(b"TAGS"));
+    }
+
+    #[test]
+    fn hasher_passes_fingerprint_through() {
+        let key = CheetahKey::from("serializeTypeCurrentRPC");
+        let mut hasher = CheetahKeyBuildHasher.build_hasher();
+        hasher.write_u64(key.fingerprint());
+        let passed_through = hasher.finish();
+        hasher.write(b"more");
+
+        assert_eq!(passed_through, key.fingerprint());
+        assert_ne!(hasher.finish(), key.fingerprint());
+    }
+}
@@ -0,0 +1,349 @@
+//! Compile-time registries of well-known strings.
+
+use core::fmt;
+
+use crate::hash::{finalize, hash_words, FINAL_MULTIPLIER};
+
+#[cfg(doc)]
+use crate::CheetahString;
+
+/// Placement attempts with fresh seeds before `known_strings!` gives up.
+const MAX_SEEDS: u64 = 64;
+
+/// A fixed set of `&'static str` values with a perfect-hash lookup.
+///
+/// Declare a registry with [`crate::known_strings!`]; its hash table is
+/// computed while compiling, so lookups never allocate and cost one hash of
+/// the input plus a single comparison against the only candidate.
+/// [`CheetahString::from_known_or_slice`] uses a registry to turn decoded
+/// text back into the declared `'static` value, so every decoded copy of a
+/// well-known key shares one pointer.
+///
+/// The table is sized for vocabularies of up to a few thousand entries.
+/// Larger or runtime-defined sets belong in a
+/// [`CheetahInterner`](crate::CheetahInterner) or a hash map.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{known_strings, CheetahString};
+///
+/// known_strings! {
+///     static PROPERTY_KEYS = ["KEYS", "TAGS", "WAIT", "REAL_TOPIC"];
+/// }
+///
+/// assert_eq!(PROPERTY_KEYS.get("TAGS"), Some("TAGS"));
+/// assert_eq!(PROPERTY_KEYS.get("tags"), None);
+///
+/// let decoded = String::from("REAL_TOPIC");
+/// let key = CheetahString::from_known_or_slice(&PROPERTY_KEYS, &decoded);
+/// assert_eq!(key.as_ptr(), PROPERTY_KEYS.get("REAL_TOPIC").unwrap().as_ptr());
+/// ```
+pub struct KnownStrings {
+    words: &'static [&'static str],
+    pilots: &'static [u16],
+    slots: &'static [u16],
+    seed: u64,
+}
+
+impl KnownStrings {
+    /// Returns the registered value equal to `value`, if any.
+    #[inline]
+    pub fn get(&self, value: &str) -> Option<&'static str> {
+        let word = *self.words.get(self.index(value)?)?;
+        Some(word)
+    }
+
+    /// Returns the declaration-order position of `value`, if it is
+    /// registered.
+    #[inline]
+    pub fn index(&self, value: &str) -> Option<usize> {
+        if self.words.is_empty() {
+            return None;
+        }
+
+        let hash = hash(value.as_bytes(), self.seed);
+        let pilot = self.pilots[bucket(hash, self.pilots.len())];
+        let entry = self.slots[slot(hash, pilot, self.slots.len())];
+        let index = usize::from(entry).checked_sub(1)?;
+        (self.words[index] == value).then_some(index)
+    }
+
+    /// Returns whether `value` is registered.
+    #[inline]
+    pub fn contains(&self, value: &str) -> bool {
+        self.index(value).is_some()
+    }
+
+    /// Returns the registered values in declaration order.
+    #[inline]
+    pub const fn as_slice(&self) -> &'static [&'static str] {
+        self.words
+    }
+
+    /// Returns the number of registered values.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns whether the registry is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Number of displacement buckets for `len` words.
+    #[doc(hidden)]
+    pub const fn __bucket_count(len: usize) -> usize {
+        if len == 0 {
+            1
+        } else {
+            len.div_ceil(2)
+        }
+    }
+
+    /// Number of table slots for `len` words: a power of two with a load
+    /// factor of at most one half.
+    #[doc(hidden)]
+    pub const fn __slot_count(len: usize) -> usize {
+        if len == 0 {
+            1
+        } else {
+            (2 * len).next_power_of_two()
+        }
+    }
+
+    /// Computes the perfect-hash table for `words`.
+    ///
+    /// Support function for [`crate::known_strings!`]; not part of the
+    /// public API.
+    ///
+    /// # Panics
+    ///
+    /// Panics, which is a compile error in the macro, if `words` contains a
+    /// duplicate or more than `u16::MAX - 1` entries.
+    #[doc(hidden)]
+    pub const fn __build<const B: usize, const S: usize>(
+        words: &[&str],
+    ) -> ([u16; B], [u16; S], u64) {
+        assert!(
+            words.len() < u16::MAX as usize,
+            "too many known strings for one registry"
+        );
+        assert!(
+            B == Self::__bucket_count(words.len()) && S == Self::__slot_count(words.len()),
+            "known string table has the wrong size"
+        );
+
+        let mut seed = 0;
+        while seed < MAX_SEEDS {
+            if let Some((pilots, slots)) = place::<B, S>(words, seed) {
+                return (pilots, slots, seed);
+            }
+            seed += 1;
+        }
+        panic!("could not build a perfect hash for the known strings");
+    }
+
+    /// Assembles a registry from a table computed by [`KnownStrings::__build`].
+    #[doc(hidden)]
+    pub const fn __from_table(
+        words: &'static [&'static str],
+        pilots: &'static [u16],
+        slots: &'static [u16],
+        seed: u64,
+    ) -> Self {
+        Self {
+            words,
+            pilots,
+            slots,
+            seed,
+        }
+    }
+}
+
+impl fmt::Debug for KnownStrings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.words).finish()
+    }
+}
+
+/// Assigns every word a distinct slot, processing the fullest buckets
+/// first and searching each bucket for a pilot that moves all of its words
+/// onto free slots. Returns `None` when `seed` needs to change.
+///
+/// `S` is at least twice the word count, so `S`-sized scratch arrays hold
+/// one entry per word.
+const fn place<const B: usize, const S: usize>(
+    words: &[&str],
+    seed: u64,
+) -> Option<([u16; B], [u16; S])> {
+    let mut pilots = [0u16; B];
+    let mut slots = [0u16; S];
+    if words.is_empty() {
+        return Some((pilots, slots));
+    }
+
+    let mut hashes = [0u64; S];
+    let mut sizes = [0usize; B];
+    let mut largest = 0;
+    let mut i = 0;
+    while i < words.len() {
+        hashes[i] = hash(words[i].as_bytes(), seed);
+        let b = bucket(hashes[i], B);
+        sizes[b] += 1;
+        if sizes[b] > largest {
+            largest = sizes[b];
+        }
+        i += 1;
+    }
+
+    // Group word indices by bucket: bucket `b` owns
+    // `members[starts[b]..starts[b] + sizes[b]]`.
+    let mut starts = [0usize; B];
+    let mut b = 1;
+    while b < B {
+        starts[b] = starts[b - 1] + sizes[b - 1];
+        b += 1;
+    }
+    let mut members = [0usize; S];
+    let mut filled = [0usize; B];
+    i = 0;
+    while i < words.len() {
+        let b = bucket(hashes[i], B);
+        members[starts[b] + filled[b]] = i;
+        filled[b] += 1;
+        i += 1;
+    }
+
+    let mut size = largest;
+    while size > 0 {
+        let mut b = 0;
+        while b < B {
+            if sizes[b] == size {
+                let group = window(&members, starts[b], size);
+                if !distinct_hashes(words, &hashes, group) {
+                    return None;
+                }
+                let pilot = match find_pilot(&hashes, group, &slots) {
+                    Some(pilot) => pilot,
+                    None => return None,
+                };
+                pilots[b] = pilot;
+                let mut k = 0;
+                while k < group.len() {
+                    let i = group[k];
+                    slots[slot(hashes[i], pilot, S)] = i as u16 + 1;
+                    k += 1;
+                }
+            }
+            b += 1;
+        }
+        size -= 1;
+    }
+
+    Some((pilots, slots))
+}
+
+/// Returns `members[start..start + len]`.
+const fn window(members: &[usize], start: usize, len: usize) -> &[usize] {
+    members.split_at(start).1.split_at(len).0
+}
+
+/// Returns whether the words of one bucket have distinct hashes, panicking
+/// on a duplicate word, which no pilot or seed can separate.
+const fn distinct_hashes(words: &[&str], hashes: &[u64], group: &[usize]) -> bool {
+    let mut k = 1;
+    while k < group.len() {
+        let mut m = 0;
+        while m < k {
+            let (i, j) = (group[k], group[m]);
+            if hashes[i] == hashes[j] {
+                assert!(!bytes_eq(words[i], words[j]), "duplicate known string");
+                return false;
+            }
+            m += 1;
+        }
+        k += 1;
+    }
+    true
+}
+
+/// Returns the first pilot that sends every word of `group` to a distinct
+/// free slot.
+const fn find_pilot<const S: usize>(
+    hashes: &[u64],
+    group: &[usize],
+    slots: &[u16; S],
+) -> Option<u16> {
+    let mut pilot = 0u16;
+    loop {
+        if fits(hashes, group, slots, pilot) {
+            return Some(pilot);
+        }
+        if pilot == u16::MAX {
+            return None;
+        }
+        pilot += 1;
+    }
+}
+
+const fn fits<const S: usize>(
+    hashes: &[u64],
+    group: &[usize],
+    slots: &[u16; S],
+    pilot: u16,
+) -> bool {
+    let mut k = 0;
+    while k < group.len() {
+        let s = slot(hashes[group[k]], pilot, S);
+        if slots[s] != 0 {
+            return false;
+        }
+        let mut m = 0;
+        while m < k {
+            if slot(hashes[group[m]], pilot, S) == s {
+                return false;
+            }
+            m += 1;
+        }
+        k += 1;
+    }
+    true
+}
+
+const fn bytes_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[inline]
+const fn bucket(hash: u64, buckets: usize) -> usize {
+    ((hash >> 32) % buckets as u64) as usize
+}
+
+#[inline]
+const fn slot(hash: u64, pilot: u16, slots: usize) -> usize {
+    let displacement = (pilot as u64 + 1).wrapping_mul(FINAL_MULTIPLIER);
+    (finalize(hash ^ displacement) as usize) & (slots - 1)
+}
+
+/// Word-at-a-time hash that can run both at compile time and at lookup.
+#[inline]
+const fn hash(bytes: &[u8], seed: u64) -> u64 {
+    hash_words(
+        seed.wrapping_mul(FINAL_MULTIPLIER) ^ (bytes.len() as u64),
+        bytes,
+    )
+}
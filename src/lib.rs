@@ -7,9 +7,14 @@
 //!
 //! - values up to 23 bytes are stored inline;
 //! - static values borrow their `&'static str`;
-//! - other long values use a shared `Arc<str>` backing.
+//! - other long values use a thin reference-counted backing;
+//! - long substrings of shared values borrow the parent backing.
 //!
-//! Long clones are bounded O(1) and allocate zero times. Append-heavy
+//! The value is 24 bytes on 64-bit and 32-bit targets, and the inline length
+//! byte provides a niche so `Option<CheetahString>` is 24 bytes as well.
+//!
+//! Long clones and substrings are bounded O(1) and allocate zero times;
+//! [`CheetahString::detach`] releases a large parent allocation. Append-heavy
 //! construction belongs to [`CheetahBuilder`]; call
 //! [`CheetahBuilder::finish`] to freeze the value or
 //! [`CheetahBuilder::into_string`] when mutation or spare capacity must
@@ -18,14 +23,19 @@
 //!
 //! The crate supports `no_std + alloc`. Optional `serde` integration preserves
 //! the text contract, while the `bytes` feature exposes [`CheetahBytes`] for
-//! byte-oriented data. Byte-to-text conversion validates and copies; only
-//! `bytes::Bytes <-> CheetahBytes` is zero-copy.
+//! byte-oriented data. Byte-to-text conversion always validates UTF-8;
+//! `try_from_shared_bytes` and the `TryFrom` conversions then share the
+//! `bytes::Bytes` buffer instead of copying it, while the `try_copy_*`
+//! methods remain explicit copy boundaries.
 //!
 //! # Split capability
 //!
 //! [`CheetahString::split_char`] returns a double-ended standard iterator.
 //! [`CheetahString::split_str`] is forward-only, so unsupported reverse
 //! iteration fails at compile time rather than panicking at runtime.
+//! [`CheetahString::rsplit`] and the rest of the `str` pattern family accept
+//! `char`, string, char-set, and closure patterns; char-like patterns keep
+//! double-ended iteration.
 //!
 //! # Search and experimental SIMD
 //!
@@ -64,11 +74,22 @@
 extern crate alloc;
 
 mod builder;
+mod case_insensitive;
 mod cheetah_string;
 mod error;
+mod hash;
+mod heap_size;
 mod inline;
+mod key;
+mod known;
+mod macros;
+mod num;
+mod range;
 mod search;
 
+#[cfg(feature = "std")]
+mod interner;
+
 #[cfg(feature = "bytes")]
 #[path = "bytes.rs"]
 mod cheetah_bytes;
@@ -82,12 +103,30 @@ mod simd;
 #[cfg(feature = "experimental-packed")]
 pub mod packed;
 
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(not(feature = "metrics"))]
+mod metrics;
+
 #[cfg(feature = "bytes")]
 pub use cheetah_bytes::{CheetahBytes, FromUtf8BytesError};
 
 pub use builder::CheetahBuilder;
-pub use cheetah_string::{CheetahString, SplitPattern, SplitStr, StrPattern};
+pub use case_insensitive::{
+    AsciiFolding, CaseFolding, CaseInsensitive, CaseInsensitiveStr, UnicodeFolding,
+};
+pub use cheetah_string::{
+    CheetahString, CheetahString15, CheetahString31, CheetahString47, CheetahStringN, JoinCheetah,
+    MatchIndices, Matches, OwnedPieces, RMatchIndices, RMatches, RSplit, RSplitN, RSplitTerminator,
+    Split, SplitInclusive, SplitN, SplitPattern, SplitStr, SplitTerminator, StorageKind,
+    StrPattern,
+};
 pub use error::{Error, Result};
+pub use heap_size::{HeapSize, MemoryTally};
+#[cfg(feature = "std")]
+pub use interner::CheetahInterner;
+pub use key::{CheetahKey, CheetahKeyBuildHasher, CheetahKeyHasher, CheetahKeyStr};
+pub use known::KnownStrings;
 pub use search::CheetahFinder;
 
 /// Deprecated v3 compatibility name for [`CheetahString`].
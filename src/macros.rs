@@ -0,0 +1,152 @@
+/// Creates a [`CheetahString`](crate::CheetahString) using `format!` syntax.
+///
+/// The text is formatted straight into inline storage when it fits in 23
+/// bytes. Longer output is written into a single right-sized shared
+/// allocation instead of going through a temporary `String`.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::cheetah_format;
+///
+/// let topic = "orders";
+/// let key = cheetah_format!("{topic}-{}", 7);
+/// assert_eq!(key, "orders-7");
+/// ```
+#[macro_export]
+macro_rules! cheetah_format {
+    ($($arg:tt)*) => {
+        $crate::CheetahString::from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+/// Concatenates string-like values into a [`CheetahString`](crate::CheetahString).
+///
+/// Each argument may be anything that implements `AsRef<str>`. The exact
+/// length is computed first, so the result is written inline or into a
+/// single shared allocation.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{cheetah_concat, CheetahString};
+///
+/// let topic = CheetahString::from("orders");
+/// let group = String::from("group-a");
+/// let route = cheetah_concat!(topic, "@", group);
+/// assert_eq!(route, "orders@group-a");
+/// ```
+#[macro_export]
+macro_rules! cheetah_concat {
+    ($($part:expr),* $(,)?) => {
+        $crate::CheetahString::__from_parts(&[$(::core::convert::AsRef::<str>::as_ref(&$part)),*])
+    };
+}
+
+/// Creates an inline [`CheetahString`](crate::CheetahString) from a string
+/// constant at compile time.
+///
+/// The text is copied into the value itself, so the macro can initialize
+/// `const` and `static` items and reading the result never follows a
+/// pointer. Text longer than 23 bytes is a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{cheetah, CheetahString};
+///
+/// static TOPIC: CheetahString = cheetah!("orders");
+/// assert_eq!(TOPIC.as_str(), "orders");
+/// ```
+///
+/// ```compile_fail
+/// use cheetah_string::{cheetah, CheetahString};
+///
+/// static TOO_LONG: CheetahString = cheetah!("a literal longer than the inline capacity");
+/// ```
+#[macro_export]
+macro_rules! cheetah {
+    ($value:expr $(,)?) => {{
+        const VALUE: $crate::CheetahString = $crate::CheetahString::inline_from_str($value);
+        VALUE
+    }};
+}
+
+/// Concatenates string constants into an inline
+/// [`CheetahString`](crate::CheetahString) at compile time.
+///
+/// Each argument must be a `&'static str` constant expression: a literal or
+/// a `const` item. As with [`cheetah!`], a result longer than 23 bytes is a
+/// compile error.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{cheetah_const, CheetahString};
+///
+/// const TOPIC: &str = "orders";
+/// const GROUP: &str = "group-a";
+/// const ROUTE: CheetahString = cheetah_const!(TOPIC, "@", GROUP);
+/// assert_eq!(ROUTE, "orders@group-a");
+/// ```
+///
+/// ```compile_fail
+/// use cheetah_string::{cheetah_const, CheetahString};
+///
+/// const ROUTE: CheetahString = cheetah_const!("a-long-topic-name", "@", "a-long-group");
+/// ```
+#[macro_export]
+macro_rules! cheetah_const {
+    ($($part:expr),+ $(,)?) => {{
+        const VALUE: $crate::CheetahString = $crate::CheetahString::__inline_from_parts(&[$($part),+]);
+        VALUE
+    }};
+}
+
+/// Declares [`KnownStrings`](crate::KnownStrings) registries of well-known
+/// string constants.
+///
+/// The perfect-hash table is computed at compile time. A duplicate entry is
+/// a compile error.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::{known_strings, CheetahString};
+///
+/// const KEYS: &str = "KEYS";
+///
+/// known_strings! {
+///     /// Property keys every message may carry.
+///     pub static PROPERTY_KEYS = [KEYS, "TAGS", "WAIT", "REAL_TOPIC"];
+///     static HEADER_KEYS = ["serializeTypeCurrentRPC", "opaque"];
+/// }
+///
+/// let key = CheetahString::from_known_or_slice(&PROPERTY_KEYS, "TAGS");
+/// assert_eq!(key.as_ptr(), PROPERTY_KEYS.get("TAGS").unwrap().as_ptr());
+/// assert!(HEADER_KEYS.contains("opaque"));
+/// ```
+///
+/// ```compile_fail
+/// cheetah_string::known_strings! {
+///     static KEYS = ["TAGS", "KEYS", "TAGS"];
+/// }
+/// ```
+#[macro_export]
+macro_rules! known_strings {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident = [$($word:expr),* $(,)?];
+    )*) => {$(
+        $(#[$attr])*
+        $vis static $name: $crate::KnownStrings = {
+            const WORDS: &[&str] = &[$($word),*];
+            const TABLE: (
+                [u16; $crate::KnownStrings::__bucket_count(WORDS.len())],
+                [u16; $crate::KnownStrings::__slot_count(WORDS.len())],
+                u64,
+            ) = $crate::KnownStrings::__build(WORDS);
+            $crate::KnownStrings::__from_table(WORDS, &TABLE.0, &TABLE.1, TABLE.2)
+        };
+    )*};
+}
@@ -0,0 +1,220 @@
+//! Process-wide construction counters, enabled by the `metrics` feature.
+//!
+//! Every counted constructor records the storage kind of the value it
+//! returns, and every shared block allocation records its size. The
+//! counters are relaxed atomics that services can export to their own
+//! telemetry with [`snapshot`]; [`reset`] zeroes them.
+//!
+//! Counted constructors are listed by [`Constructor`]. Values derived from
+//! existing ones (clones, substrings, case transforms, `+`) and values built
+//! by `const fn` constructors such as `from_static_str` or `cheetah!` are not
+//! counted as created, but any shared block they allocate is still counted.
+//!
+//! Without the feature the recording hooks are empty inline functions, so
+//! they compile away entirely.
+
+#![cfg_attr(not(feature = "metrics"), allow(dead_code))]
+
+#[cfg(feature = "metrics")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::StorageKind;
+
+/// The constructor families counted by the `metrics` feature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Constructor {
+    /// `from_slice` and the `From<&str>`, `From<&String>` and `FromStr`
+    /// conversions.
+    FromSlice,
+    /// `from_string`, `from_arc_string`, and the `From<String>` and
+    /// `From<char>` conversions.
+    FromString,
+    /// `try_from_vec`, `try_from_arc_vec`, and their unchecked forms.
+    FromVec,
+    /// `try_from_bytes`, the `bytes::Bytes` constructors, and their
+    /// unchecked forms.
+    FromBytes,
+    /// `from_known_or_slice`.
+    Known,
+    /// `CheetahBuilder::finish`.
+    Builder,
+    /// `from_fmt` and `cheetah_format!`.
+    Format,
+    /// `join`, `concat`, and `cheetah_concat!`; a single-part `join` or
+    /// `concat` returns a clone and is not counted.
+    Concat,
+    /// The numeric constructors and `From` conversions.
+    Number,
+}
+
+impl Constructor {
+    /// Every counted constructor, in declaration order.
+    pub const ALL: [Constructor; 9] = [
+        Constructor::FromSlice,
+        Constructor::FromString,
+        Constructor::FromVec,
+        Constructor::FromBytes,
+        Constructor::Known,
+        Constructor::Builder,
+        Constructor::Format,
+        Constructor::Concat,
+        Constructor::Number,
+    ];
+}
+
+const KINDS: usize = 4;
+const CONSTRUCTORS: usize = Constructor::ALL.len();
+
+#[inline]
+const fn kind_index(kind: StorageKind) -> usize {
+    match kind {
+        StorageKind::Inline => 0,
+        StorageKind::Static => 1,
+        StorageKind::Shared => 2,
+        StorageKind::Sliced => 3,
+    }
+}
+
+#[cfg(feature = "metrics")]
+struct Counters {
+    created: [AtomicU64; KINDS],
+    constructed: [AtomicU64; CONSTRUCTORS],
+    shared_allocations: AtomicU64,
+    shared_bytes: AtomicU64,
+}
+
+#[cfg(feature = "metrics")]
+#[allow(clippy::declare_interior_mutable_const)]
+const ZERO: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "metrics")]
+static COUNTERS: Counters = Counters {
+    created: [ZERO; KINDS],
+    constructed: [ZERO; CONSTRUCTORS],
+    shared_allocations: ZERO,
+    shared_bytes: ZERO,
+};
+
+/// Records a value of `kind` returned by `constructor`.
+#[inline]
+pub(crate) fn record_value(constructor: Constructor, kind: StorageKind) {
+    #[cfg(feature = "metrics")]
+    {
+        COUNTERS.created[kind_index(kind)].fetch_add(1, Ordering::Relaxed);
+        COUNTERS.constructed[constructor as usize].fetch_add(1, Ordering::Relaxed);
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = (constructor, kind);
+}
+
+/// Records a shared block allocation of `bytes` bytes.
+#[inline]
+pub(crate) fn record_allocation(bytes: usize) {
+    #[cfg(feature = "metrics")]
+    {
+        COUNTERS.shared_allocations.fetch_add(1, Ordering::Relaxed);
+        COUNTERS
+            .shared_bytes
+            .fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = bytes;
+}
+
+/// Point-in-time copy of the process-wide counters.
+///
+/// Counters are read one at a time, so a snapshot taken while other threads
+/// construct values may mix slightly different moments.
+#[cfg(feature = "metrics")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MetricsSnapshot {
+    created: [u64; KINDS],
+    constructed: [u64; CONSTRUCTORS],
+    shared_allocations: u64,
+    shared_bytes: u64,
+}
+
+#[cfg(feature = "metrics")]
+impl MetricsSnapshot {
+    /// Returns how many counted values were created with storage `kind`.
+    #[inline]
+    pub fn created(&self, kind: StorageKind) -> u64 {
+        self.created[kind_index(kind)]
+    }
+
+    /// Returns how many values `constructor` returned.
+    #[inline]
+    pub fn constructed(&self, constructor: Constructor) -> u64 {
+        self.constructed[constructor as usize]
+    }
+
+    /// Returns how many shared blocks were allocated.
+    #[inline]
+    pub fn shared_allocations(&self) -> u64 {
+        self.shared_allocations
+    }
+
+    /// Returns the total size of all shared blocks allocated, including
+    /// their headers.
+    #[inline]
+    pub fn shared_bytes_allocated(&self) -> u64 {
+        self.shared_bytes
+    }
+
+    /// Returns the counts accumulated between `earlier` and this snapshot.
+    ///
+    /// Counters that were reset in between saturate at zero.
+    pub fn since(&self, earlier: &MetricsSnapshot) -> MetricsSnapshot {
+        let mut delta = MetricsSnapshot::default();
+        for (i, count) in delta.created.iter_mut().enumerate() {
+            *count = self.created[i].saturating_sub(earlier.created[i]);
+        }
+        for (i, count) in delta.constructed.iter_mut().enumerate() {
+            *count = self.constructed[i].saturating_sub(earlier.constructed[i]);
+        }
+        delta.shared_allocations = self
+            .shared_allocations
+            .saturating_sub(earlier.shared_allocations);
+        delta.shared_bytes = self.shared_bytes.saturating_sub(earlier.shared_bytes);
+        delta
+    }
+}
+
+/// Returns the current counter values.
+///
+/// # Examples
+///
+/// ```
+/// use cheetah_string::metrics::{self, Constructor};
+/// use cheetah_string::{CheetahString, StorageKind};
+///
+/// let before = metrics::snapshot();
+/// let _short = CheetahString::from_slice("orders");
+/// let _long = CheetahString::from_string("orders-".repeat(8));
+/// let delta = metrics::snapshot().since(&before);
+///
+/// assert!(delta.constructed(Constructor::FromSlice) >= 1);
+/// assert!(delta.created(StorageKind::Shared) >= 1);
+/// assert!(delta.shared_bytes_allocated() >= 56);
+/// ```
+#[cfg(feature = "metrics")]
+pub fn snapshot() -> MetricsSnapshot {
+    let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+    MetricsSnapshot {
+        created: core::array::from_fn(|i| load(&COUNTERS.created[i])),
+        constructed: core::array::from_fn(|i| load(&COUNTERS.constructed[i])),
+        shared_allocations: load(&COUNTERS.shared_allocations),
+        shared_bytes: load(&COUNTERS.shared_bytes),
+    }
+}
+
+/// Zeroes every counter.
+#[cfg(feature = "metrics")]
+pub fn reset() {
+    let zero = |counter: &AtomicU64| counter.store(0, Ordering::Relaxed);
+    COUNTERS.created.iter().for_each(zero);
+    COUNTERS.constructed.iter().for_each(zero);
+    zero(&COUNTERS.shared_allocations);
+    zero(&COUNTERS.shared_bytes);
+}
@@ -0,0 +1,398 @@
+//! Allocation-free integer formatting and position-reporting number parsing.
+
+use core::str;
+
+use crate::{Error, Result};
+
+/// Longest decimal rendering of a `u64` or `i64` (`-9223372036854775808`).
+pub(crate) const MAX_DEC_LEN: usize = 20;
+
+/// Longest decimal rendering of a `u128` or `i128`.
+pub(crate) const MAX_DEC128_LEN: usize = 40;
+
+/// Longest hexadecimal rendering of a `u64`.
+pub(crate) const MAX_HEX_LEN: usize = 16;
+
+/// The decimal pairs `00` through `99`, so two digits are emitted per
+/// division.
+const DEC_PAIRS: &[u8; 200] = b"0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+macro_rules! format_unsigned {
+    ($name:ident, $ty:ty, $len:expr) => {
+        /// Writes `value` in decimal to the end of `buf` and returns the
+        /// digits.
+        pub(crate) fn $name(mut value: $ty, buf: &mut [u8; $len]) -> &str {
+            let mut pos = buf.len();
+            while value >= 100 {
+                let pair = (value % 100) as usize * 2;
+                value /= 100;
+                pos -= 2;
+                buf[pos..pos + 2].copy_from_slice(&DEC_PAIRS[pair..pair + 2]);
+            }
+            if value >= 10 {
+                let pair = value as usize * 2;
+                pos -= 2;
+                buf[pos..pos + 2].copy_from_slice(&DEC_PAIRS[pair..pair + 2]);
+            } else {
+                pos -= 1;
+                buf[pos] = b'0' + value as u8;
+            }
+
+            // SAFETY: only ASCII digits were written to `buf[pos..]`.
+            unsafe { str::from_utf8_unchecked(&buf[pos..]) }
+        }
+    };
+}
+
+macro_rules! format_signed {
+    ($name:ident, $ty:ty, $unsigned:ident, $len:expr) => {
+        /// Writes `value` in decimal to the end of `buf` and returns the text.
+        pub(crate) fn $name(value: $ty, buf: &mut [u8; $len]) -> &str {
+            let len = $unsigned(value.unsigned_abs(), buf).len();
+            let mut pos = buf.len() - len;
+            if value < 0 {
+                // The minimum value has one digit less than the buffer, so
+                // there is always room for the sign.
+                pos -= 1;
+                buf[pos] = b'-';
+            }
+
+            // SAFETY: `buf[pos..]` holds ASCII digits and an optional sign.
+            unsafe { str::from_utf8_unchecked(&buf[pos..]) }
+        }
+    };
+}
+
+format_unsigned!(format_u64, u64, MAX_DEC_LEN);
+format_unsigned!(format_u128, u128, MAX_DEC128_LEN);
+format_signed!(format_i64, i64, format_u64, MAX_DEC_LEN);
+format_signed!(format_i128, i128, format_u128, MAX_DEC128_LEN);
+
+/// Writes `value` in lowercase hexadecimal, without a prefix, to the end of
+/// `buf` and returns the digits.
+pub(crate) fn format_hex(mut value: u64, buf: &mut [u8; MAX_HEX_LEN]) -> &str {
+    let mut pos = buf.len();
+    loop {
+        pos -= 1;
+        buf[pos] = HEX_DIGITS[(value & 0xf) as usize];
+        value >>= 4;
+        if value == 0 {
+            break;
+        }
+    }
+
+    // SAFETY: only ASCII hex digits were written to `buf[pos..]`.
+    unsafe { str::from_utf8_unchecked(&buf[pos..]) }
+}
+
+/// Numbers `0..SMALL_NUMBERS` are served from a static table.
+pub(crate) const SMALL_NUMBERS: usize = 1025;
+
+/// Digits of `0..SMALL_NUMBERS` written back to back.
+static SMALL_DIGITS: [u8; small_digits_len()] = small_digits();
+
+/// Start of each number in `SMALL_DIGITS`, plus the end of the last one.
+static SMALL_OFFSETS: [u16; SMALL_NUMBERS + 1] = small_offsets();
+
+const fn decimal_len(mut value: usize) -> usize {
+    let mut len = 1;
+    while value >= 10 {
+        value /= 10;
+        len += 1;
+    }
+    len
+}
+
+const fn small_digits_len() -> usize {
+    let mut len = 0;
+    let mut value = 0;
+    while value < SMALL_NUMBERS {
+        len += decimal_len(value);
+        value += 1;
+    }
+    len
+}
+
+const fn small_digits() -> [u8; small_digits_len()] {
+    let mut digits = [0; small_digits_len()];
+    let mut pos = 0;
+    let mut value = 0;
+    while value < SMALL_NUMBERS {
+        let len = decimal_len(value);
+        let mut rest = value;
+        let mut i = len;
+        while i > 0 {
+            i -= 1;
+            digits[pos + i] = b'0' + (rest % 10) as u8;
+            rest /= 10;
+        }
+        pos += len;
+        value += 1;
+    }
+    digits
+}
+
+const fn small_offsets() -> [u16; SMALL_NUMBERS + 1] {
+    let mut offsets = [0; SMALL_NUMBERS + 1];
+    let mut value = 0;
+    while value < SMALL_NUMBERS {
+        offsets[value + 1] = offsets[value] + decimal_len(value) as u16;
+        value += 1;
+    }
+    offsets
+}
+
+/// Returns the static decimal text of `value` when it is in the small-number
+/// table.
+#[inline]
+pub(crate) fn small_number(value: u128) -> Option<&'static str> {
+    let index = usize::try_from(value).ok().filter(|&v| v < SMALL_NUMBERS)?;
+    let start = SMALL_OFFSETS[index] as usize;
+    let end = SMALL_OFFSETS[index + 1] as usize;
+    // SAFETY: the table only contains ASCII digits.
+    Some(unsafe { str::from_utf8_unchecked(&SMALL_DIGITS[start..end]) })
+}
+
+macro_rules! parse_unsigned {
+    ($($name:ident => $ty:ty),* $(,)?) => {
+        $(
+            /// Parses an optionally `+`-prefixed decimal integer.
+            pub(crate) fn $name(text: &str) -> Result<$ty> {
+                let bytes = text.as_bytes();
+                let mut index = usize::from(bytes.first() == Some(&b'+'));
+                if index == bytes.len() {
+                    return Err(Error::InvalidNumber { index });
+                }
+
+                let mut value: $ty = 0;
+                while index < bytes.len() {
+                    let digit = bytes[index].wrapping_sub(b'0');
+                    if digit > 9 {
+                        return Err(Error::InvalidNumber { index });
+                    }
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(<$ty>::from(digit)))
+                        .ok_or(Error::NumberOverflow { index })?;
+                    index += 1;
+                }
+                Ok(value)
+            }
+        )*
+    };
+}
+
+macro_rules! parse_signed {
+    ($($name:ident => $ty:ty),* $(,)?) => {
+        $(
+            /// Parses an optionally signed decimal integer.
+            pub(crate) fn $name(text: &str) -> Result<$ty> {
+                let bytes = text.as_bytes();
+                let negative = bytes.first() == Some(&b'-');
+                let mut index = usize::from(negative || bytes.first() == Some(&b'+'));
+                if index == bytes.len() {
+                    return Err(Error::InvalidNumber { index });
+                }
+
+                // Accumulate towards the sign so the minimum value parses.
+                let mut value: $ty = 0;
+                while index < bytes.len() {
+                    let digit = bytes[index].wrapping_sub(b'0');
+                    if digit > 9 {
+                        return Err(Error::InvalidNumber { index });
+                    }
+                    let digit = digit as $ty;
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|value| {
+                            if negative {
+                                value.checked_sub(digit)
+                            } else {
+                                value.checked_add(digit)
+                            }
+                        })
+                        .ok_or(Error::NumberOverflow { index })?;
+                    index += 1;
+                }
+                Ok(value)
+            }
+        )*
+    };
+}
+
+parse_unsigned! {
+    parse_u8 => u8,
+    parse_u16 => u16,
+    parse_u32 => u32,
+    parse_u64 => u64,
+    parse_u128 => u128,
+    parse_usize => usize,
+}
+
+parse_signed! {
+    parse_i8 => i8,
+    parse_i16 => i16,
+    parse_i32 => i32,
+    parse_i64 => i64,
+    parse_i128 => i128,
+    parse_isize => isize,
+}
+
+/// Parses `true` or `false`, reporting the first byte that matches neither.
+pub(crate) fn parse_bool(text: &str) -> Result<bool> {
+    match text {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => {
+            let index = ["true", "false"]
+                .iter()
+                .map(|word| common_prefix_len(text, word))
+                .max()
+                .unwrap_or(0);
+            Err(Error::InvalidBool { index })
+        }
+    }
+}
+
+/// Parses a float with the grammar of `str::parse::<f64>`.
+pub(crate) fn parse_f64(text: &str) -> Result<f64> {
+    text.parse().map_err(|_| Error::InvalidNumber {
+        index: float_error_index(text),
+    })
+}
+
+/// Parses a float with the grammar of `str::parse::<f32>`.
+pub(crate) fn parse_f32(text: &str) -> Result<f32> {
+    text.parse().map_err(|_| Error::InvalidNumber {
+        index: float_error_index(text),
+    })
+}
+
+fn common_prefix_len(text: &str, word: &str) -> usize {
+    text.bytes()
+        .zip(word.bytes())
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+/// Finds the first byte of `text` that breaks the float grammar, or
+/// `text.len()` when the input ends early.
+fn float_error_index(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let mut index = usize::from(matches!(bytes.first(), Some(b'+' | b'-')));
+
+    let rest = &text[index..];
+    for word in ["infinity", "inf", "nan"] {
+        let matched = rest
+            .bytes()
+            .zip(word.bytes())
+            .take_while(|(a, b)| a.eq_ignore_ascii_case(b))
+            .count();
+        if matched > 0 {
+            // A word was started: it is either complete with trailing bytes
+            // or broken at the first mismatch.
+            return index + matched;
+        }
+    }
+
+    let digits_before = count_digits(&bytes[index..]);
+    index += digits_before;
+    let mut digits_after = 0;
+    if bytes.get(index) == Some(&b'.') {
+        index += 1;
+        digits_after = count_digits(&bytes[index..]);
+        index += digits_after;
+    }
+    if digits_before + digits_after == 0 {
+        // The mantissa needs at least one digit.
+        return index;
+    }
+
+    if matches!(bytes.get(index), Some(b'e' | b'E')) {
+        index += 1;
+        if matches!(bytes.get(index), Some(b'+' | b'-')) {
+            index += 1;
+        }
+        let exponent = count_digits(&bytes[index..]);
+        if exponent == 0 {
+            return index;
+        }
+        index += exponent;
+    }
+
+    index
+}
+
+fn count_digits(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::string::ToString;
+
+    #[test]
+    fn integers_match_core_formatting() {
+        let mut buf = [0; MAX_DEC_LEN];
+        let mut wide = [0; MAX_DEC128_LEN];
+        let mut hex = [0; MAX_HEX_LEN];
+        for value in [0, 1, 9, 10, 99, 100, 101, 1_000_007, u64::MAX / 3, u64::MAX] {
+            assert_eq!(format_u64(value, &mut buf), value.to_string());
+            assert_eq!(format_hex(value, &mut hex), format!("{value:x}"));
+        }
+        for value in [0, -1, 7, -10, -99, i64::MAX, i64::MIN, i64::MIN + 1] {
+            assert_eq!(format_i64(value, &mut buf), value.to_string());
+        }
+        for value in [0, u128::from(u64::MAX) + 1, u128::MAX] {
+            assert_eq!(format_u128(value, &mut wide), value.to_string());
+        }
+        for value in [i128::MIN, -1, i128::MAX] {
+            assert_eq!(format_i128(value, &mut wide), value.to_string());
+        }
+    }
+
+    #[test]
+    fn small_number_table_covers_its_range() {
+        for value in 0..SMALL_NUMBERS {
+            assert_eq!(
+                small_number(value as u128),
+                Some(value.to_string().as_str())
+            );
+        }
+        assert_eq!(small_number(SMALL_NUMBERS as u128), None);
+    }
+
+    #[test]
+    fn float_errors_point_at_the_offending_byte() {
+        for (text, index) in [
+            ("", 0),
+            ("-", 1),
+            (".", 1),
+            ("1.5x", 3),
+            ("1e", 2),
+            ("1e+", 3),
+            ("--1", 1),
+            ("infx", 3),
+            ("nax", 2),
+            ("1.2.3", 3),
+        ] {
+            assert!(text.parse::<f64>().is_err(), "{text:?}");
+            assert_eq!(
+                parse_f64(text),
+                Err(Error::InvalidNumber { index }),
+                "{text:?}"
+            );
+        }
+    }
+}
@@ -1,7 +1,10 @@
 //! Experimental 24-byte packed string prototype.
 //!
 //! This module is available only with `feature = "experimental-packed"` and is
-//! not used by the stable `CheetahString` representation.
+//! not used by the stable `CheetahString` representation. The stable
+//! representation is now 24 bytes itself, with clone-cheap shared storage and
+//! no endianness or pointer-width restriction; this prototype is kept only
+//! for comparison benchmarks.
 
 #![cfg(all(target_pointer_width = "64", target_endian = "little"))]
 
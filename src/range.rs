@@ -0,0 +1,58 @@
+//! Byte-range validation shared by the slicing and editing APIs.
+
+use core::ops::{Bound, RangeBounds};
+
+use crate::{Error, Result};
+
+/// Converts `range` into `start..end` offsets for a value of `len` bytes.
+///
+/// Only overflowing bounds are rejected here; use [`check`] to validate the
+/// offsets against the text.
+#[inline]
+pub(crate) fn resolve<R: RangeBounds<usize>>(range: &R, len: usize) -> Result<(usize, usize)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start
+            .checked_add(1)
+            .ok_or(Error::IndexOutOfBounds { index: start, len })?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end
+            .checked_add(1)
+            .ok_or(Error::IndexOutOfBounds { index: end, len })?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    Ok((start, end))
+}
+
+/// Checks that `start..end` is ordered, in bounds, and on UTF-8 character
+/// boundaries of `value`.
+#[inline]
+pub(crate) fn check(value: &str, start: usize, end: usize) -> Result<()> {
+    let len = value.len();
+
+    if start > end {
+        return Err(Error::InvalidRange { start, end });
+    }
+
+    if start > len {
+        return Err(Error::IndexOutOfBounds { index: start, len });
+    }
+
+    if end > len {
+        return Err(Error::IndexOutOfBounds { index: end, len });
+    }
+
+    if !value.is_char_boundary(start) {
+        return Err(Error::InvalidCharBoundary { index: start });
+    }
+
+    if !value.is_char_boundary(end) {
+        return Err(Error::InvalidCharBoundary { index: end });
+    }
+
+    Ok(())
+}
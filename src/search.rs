@@ -1,3 +1,5 @@
+use alloc::boxed::Box;
+
 pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
     if needle.is_empty() {
         return Some(0);
@@ -22,17 +24,100 @@ pub(crate) fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
     memchr::memmem::rfind(haystack, needle)
 }
 
+/// Finds `needle` in `haystack`, treating ASCII letters of either case as
+/// equal.
+///
+/// Candidates are located with `memchr` on both cases of the needle's first
+/// byte and then verified. Non-ASCII bytes must match exactly, so every
+/// match starts on a character boundary.
+pub(crate) fn find_ignore_ascii_case(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let Some((&first, rest)) = needle.split_first() else {
+        return Some(0);
+    };
+    let last_start = haystack.len().checked_sub(needle.len())?;
+    let (lower, upper) = (first.to_ascii_lowercase(), first.to_ascii_uppercase());
+
+    let mut start = 0;
+    while start <= last_start {
+        let candidates = &haystack[start..=last_start];
+        let offset = if lower == upper {
+            memchr::memchr(lower, candidates)
+        } else {
+            memchr::memchr2(lower, upper, candidates)
+        }?;
+        let at = start + offset;
+        if haystack[at + 1..at + needle.len()].eq_ignore_ascii_case(rest) {
+            return Some(at);
+        }
+        start = at + 1;
+    }
+    None
+}
+
+/// Finds the first byte from a set of one to three ASCII bytes.
+pub(crate) fn find_ascii_set(haystack: &[u8], set: &[u8]) -> Option<usize> {
+    match *set {
+        [a] => memchr::memchr(a, haystack),
+        [a, b] => memchr::memchr2(a, b, haystack),
+        [a, b, c] => memchr::memchr3(a, b, c, haystack),
+        _ => haystack.iter().position(|byte| set.contains(byte)),
+    }
+}
+
+/// Finds the last byte from a set of one to three ASCII bytes.
+pub(crate) fn rfind_ascii_set(haystack: &[u8], set: &[u8]) -> Option<usize> {
+    match *set {
+        [a] => memchr::memrchr(a, haystack),
+        [a, b] => memchr::memrchr2(a, b, haystack),
+        [a, b, c] => memchr::memrchr3(a, b, c, haystack),
+        _ => haystack.iter().rposition(|byte| set.contains(byte)),
+    }
+}
+
 /// Reusable substring finder for repeated searches with the same needle.
 pub struct CheetahFinder<'a> {
     needle: &'a str,
     finder: Option<memchr::memmem::Finder<'a>>,
+    /// The needle with ASCII letters lowercased, for case-insensitive finders.
+    folded: Option<Box<[u8]>>,
 }
 
 impl<'a> CheetahFinder<'a> {
     #[inline]
     pub fn new(needle: &'a str) -> Self {
         let finder = (needle.len() > 1).then(|| memchr::memmem::Finder::new(needle.as_bytes()));
-        Self { needle, finder }
+        Self {
+            needle,
+            finder,
+            folded: None,
+        }
+    }
+
+    /// Creates a finder that treats ASCII letters of either case as equal.
+    ///
+    /// The needle is case-folded once here, so repeated searches need no
+    /// lowercased copy of each haystack. Non-ASCII characters must match
+    /// exactly. A needle without ASCII letters uses the exact search.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use cheetah_string::{CheetahFinder, CheetahString};
+    ///
+    /// let finder = CheetahFinder::new_ignore_ascii_case("content-type");
+    /// let header = CheetahString::from("X-Original-Content-Type: text/plain");
+    /// assert_eq!(finder.find_in(&header), Some(11));
+    /// ```
+    pub fn new_ignore_ascii_case(needle: &'a str) -> Self {
+        if !needle.bytes().any(|byte| byte.is_ascii_alphabetic()) {
+            return Self::new(needle);
+        }
+        let folded = needle.to_ascii_lowercase().into_bytes().into_boxed_slice();
+        Self {
+            needle,
+            finder: None,
+            folded: Some(folded),
+        }
     }
 
     #[inline]
@@ -47,6 +132,10 @@ impl<'a> CheetahFinder<'a> {
     {
         let haystack = haystack.as_ref().as_bytes();
 
+        if let Some(folded) = &self.folded {
+            return find_ignore_ascii_case(haystack, folded);
+        }
+
         if self.needle.is_empty() {
             return Some(0);
         }
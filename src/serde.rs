@@ -1,4 +1,4 @@
-use crate::CheetahString;
+use crate::{CaseInsensitive, CheetahKey, CheetahStringN};
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
@@ -6,7 +6,7 @@ use core::str;
 use serde::de::{Error as DeError, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-impl Serialize for CheetahString {
+impl<const N: usize> Serialize for CheetahStringN<N> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -15,14 +15,16 @@ impl Serialize for CheetahString {
     }
 }
 
-pub fn cheetah_string<'de: 'a, 'a, D>(deserializer: D) -> Result<CheetahString, D::Error>
+pub fn cheetah_string<'de: 'a, 'a, D, const N: usize>(
+    deserializer: D,
+) -> Result<CheetahStringN<N>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    struct CheetahStringVisitor;
+    struct CheetahStringVisitor<const N: usize>;
 
-    impl<'a> Visitor<'a> for CheetahStringVisitor {
-        type Value = CheetahString;
+    impl<'a, const N: usize> Visitor<'a> for CheetahStringVisitor<N> {
+        type Value = CheetahStringN<N>;
 
         fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
             formatter.write_str("a string")
@@ -32,21 +34,21 @@ where
         where
             E: DeError,
         {
-            Ok(CheetahString::from_slice(v))
+            Ok(CheetahStringN::from_slice(v))
         }
 
         fn visit_borrowed_str<E>(self, v: &'a str) -> Result<Self::Value, E>
         where
             E: DeError,
         {
-            Ok(CheetahString::from_slice(v))
+            Ok(CheetahStringN::from_slice(v))
         }
 
         fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
         where
             E: DeError,
         {
-            Ok(CheetahString::from_string(v))
+            Ok(CheetahStringN::from_string(v))
         }
 
         fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
@@ -54,7 +56,7 @@ where
             E: DeError,
         {
             str::from_utf8(v)
-                .map(CheetahString::from_slice)
+                .map(CheetahStringN::from_slice)
                 .map_err(DeError::custom)
         }
 
@@ -63,7 +65,7 @@ where
             E: DeError,
         {
             str::from_utf8(v)
-                .map(CheetahString::from_slice)
+                .map(CheetahStringN::from_slice)
                 .map_err(DeError::custom)
         }
 
@@ -71,13 +73,13 @@ where
         where
             E: DeError,
         {
-            CheetahString::try_from_vec(v).map_err(DeError::custom)
+            CheetahStringN::try_from_vec(v).map_err(DeError::custom)
         }
     }
-    deserializer.deserialize_str(CheetahStringVisitor)
+    deserializer.deserialize_str(CheetahStringVisitor::<N>)
 }
 
-impl<'de> Deserialize<'de> for CheetahString {
+impl<'de, const N: usize> Deserialize<'de> for CheetahStringN<N> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -85,3 +87,40 @@ impl<'de> Deserialize<'de> for CheetahString {
         cheetah_string(deserializer)
     }
 }
+
+impl Serialize for CheetahKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CheetahKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        cheetah_string(deserializer).map(CheetahKey::new)
+    }
+}
+
+/// Serializes the original spelling.
+impl<T: Serialize, F> Serialize for CaseInsensitive<T, F> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, F> Deserialize<'de> for CaseInsensitive<T, F> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(CaseInsensitive::with_folding)
+    }
+}
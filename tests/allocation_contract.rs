@@ -1,4 +1,4 @@
-use cheetah_string::CheetahString;
+use cheetah_string::{CheetahBuilder, CheetahString};
 use std::alloc::{GlobalAlloc, Layout, System};
 use std::cell::Cell;
 use std::hint::black_box;
@@ -69,6 +69,7 @@ fn assert_clone_allocations(value: &CheetahString) {
     );
     assert_eq!(cloned.as_bytes().as_ptr(), pointer);
     assert_eq!(&cloned, value);
+    assert!(value.is_static() || cloned.ptr_eq(value));
 }
 
 #[test]
@@ -123,6 +124,279 @@ fn v3_allocation_and_clone_contracts() {
     );
     assert_eq!(shared_clone, shared_value);
 
+    let parent = CheetahString::from_string("p".repeat(4096));
+    let parent_pointer = parent.as_bytes().as_ptr();
+    let (count, bytes, window) = measure(|| black_box(&parent).substring(100, 140));
+    assert_eq!(
+        (count, bytes),
+        (0, 0),
+        "Shared substring borrows the parent"
+    );
+    assert_eq!(window.as_bytes().as_ptr(), parent_pointer.wrapping_add(100));
+    assert_clone_allocations(&window);
+
+    let (count, bytes, (head, tail)) = measure(|| black_box(&parent).split_at(2048));
+    assert_eq!((count, bytes), (0, 0), "split_at halves borrow the parent");
+    assert_eq!(head.as_bytes().as_ptr(), parent_pointer);
+    assert_eq!(tail.len(), 2048);
+
+    let (count, bytes, nested) = measure(|| black_box(&tail).slice(..1024));
+    assert_eq!((count, bytes), (0, 0), "slicing a window stays zero-copy");
+    assert_eq!(
+        nested.as_bytes().as_ptr(),
+        parent_pointer.wrapping_add(2048)
+    );
+
+    let record = CheetahString::from_string(format!(
+        "{} short {}\nline-two {}",
+        "k".repeat(64),
+        "v".repeat(40),
+        "w".repeat(32)
+    ));
+    let (count, bytes, total) = measure(|| {
+        let record = black_box(&record);
+        let words: usize = record.split_owned(' ').map(|piece| piece.len()).sum();
+        let lines: usize = record.lines_owned().map(|piece| piece.len()).sum();
+        let fields: usize = record
+            .split_whitespace_owned()
+            .map(|piece| piece.len())
+            .sum();
+        let (head, tail) = record.split_once_owned('\n').unwrap();
+        words + lines + fields + head.len() + tail.len()
+    });
+    assert_eq!((count, bytes), (0, 0), "owned pieces never allocate");
+    assert!(total > 0);
+    let first = record.split_owned(' ').next().unwrap();
+    assert_eq!(first.as_bytes().as_ptr(), record.as_bytes().as_ptr());
+
+    let (count, bytes, derived) = measure(|| {
+        let base = CheetahString::from(black_box("order"));
+        let mut joined = base.clone() + black_box("@group-a");
+        joined += black_box("-1");
+        joined += &CheetahString::from(black_box("!"));
+        let joined = joined + &base;
+        let upper = joined.to_uppercase();
+        let lower = upper.to_lowercase();
+        let replaced = lower.replace("@", "::");
+        let replaced_once = replaced.replacen("o", "0", 1);
+        let repeated = base.repeat(4);
+        (joined, lower, replaced_once, repeated)
+    });
+    assert_eq!(
+        (count, bytes),
+        (0, 0),
+        "inline-sized concatenation and transforms stay off the heap"
+    );
+    assert_eq!(derived.0, "order@group-a-1!order");
+    assert_eq!(derived.1, "order@group-a-1!order");
+    assert_eq!(derived.2, "0rder::group-a-1!order");
+    assert_eq!(derived.3, "orderorderorderorder");
+
+    let topic = CheetahString::from("ORDERS.PRIMARY.REGION-A.".repeat(4));
+    let lowered = topic.to_lowercase();
+    let (count, bytes, unchanged) = measure(|| {
+        [
+            topic.to_uppercase(),
+            topic.to_ascii_uppercase(),
+            lowered.to_lowercase(),
+            topic.replace(black_box("%RETRY%"), ""),
+            topic.replacen(black_box("."), "/", 0),
+            topic.repeat(1),
+        ]
+    });
+    assert_eq!((count, bytes), (0, 0), "no-op transforms return clones");
+    for value in &unchanged {
+        assert!(value.ptr_eq(&topic) || value.ptr_eq(&lowered));
+    }
+
+    let (count, bytes, stripped) = measure(|| {
+        (
+            topic.strip_prefix_owned(black_box("ORDERS.")).unwrap(),
+            topic.trim_matches_owned('.'),
+            topic.trimmed(),
+        )
+    });
+    assert_eq!((count, bytes), (0, 0), "owned trims share the backing");
+    assert!(stripped.0.ptr_eq(&topic) && stripped.1.ptr_eq(&topic));
+
+    let (count, bytes, ch) = measure(|| CheetahString::from(black_box('火')));
+    assert_eq!((count, bytes), (0, 0), "a char is stored inline");
+    assert_eq!(ch, "火");
+
+    let (count, _, repeated) = measure(|| topic.repeat(black_box(3)));
+    assert_eq!(count, 1, "long repeats fill one right-sized backing");
+    assert_eq!(repeated, topic.as_str().repeat(3));
+
+    let (count, _, spilled) = measure(|| {
+        let mut value = CheetahString::from(black_box("0123456789abcdef"));
+        value += black_box("0123456789abcdef");
+        value
+    });
+    assert_eq!(
+        count, 1,
+        "results past the inline limit allocate one backing"
+    );
+    assert_eq!(spilled.len(), 32);
+
+    let (count, bytes, built) = measure(|| {
+        let mut builder = CheetahBuilder::new();
+        builder.push_str(black_box("orders"));
+        builder.push('@');
+        builder.push_str(black_box("group-a"));
+        builder.finish()
+    });
+    assert_eq!((count, bytes), (0, 0), "short builders never allocate");
+    assert_eq!(built, "orders@group-a");
+
+    let (count, bytes, reserved) = measure(|| {
+        let mut builder = CheetahBuilder::with_capacity(black_box(23));
+        builder.reserve(black_box(8));
+        builder.extend(black_box("consumer-group").chars());
+        builder.finish()
+    });
+    assert_eq!(
+        (count, bytes),
+        (0, 0),
+        "inline-sized capacities do not force a spill"
+    );
+    assert_eq!(reserved, "consumer-group");
+
+    let (count, bytes, edited) = measure(|| {
+        let mut builder = CheetahBuilder::from(black_box("group-a,"));
+        builder.insert_str(0, "orders@");
+        builder.truncate(builder.len() - 1);
+        let suffix = builder.drain(13..);
+        builder.replace_range(..6, "topic");
+        builder.extend_from_within(..5);
+        let tail = builder.split_off(5);
+        (builder.finish(), tail.finish(), suffix)
+    });
+    assert_eq!((count, bytes), (0, 0), "inline edits never allocate");
+    assert_eq!(edited.0, "topic");
+    assert_eq!(edited.1, "@group-topic");
+    assert_eq!(edited.2, "a");
+
+    let (count, bytes, formatted) = measure(|| {
+        use std::fmt::Write;
+
+        let mut builder = CheetahBuilder::new();
+        builder.push_str(black_box("q"));
+        builder.push_u64(black_box(42));
+        builder.push('@');
+        builder.push_f64(black_box(0.25));
+        write!(builder, "/{:x}", black_box(255u32)).unwrap();
+        builder.finish()
+    });
+    assert_eq!((count, bytes), (0, 0), "numeric pushes format in place");
+    assert_eq!(formatted, "q42@0.25/ff");
+
+    let (count, bytes, numbers) = measure(|| {
+        [
+            CheetahString::from(black_box(u64::MAX)),
+            CheetahString::from(black_box(i64::MIN)),
+            CheetahString::from(black_box(512u16)),
+            CheetahString::from(black_box(-0.125f64)),
+            CheetahString::from(black_box(true)),
+        ]
+    });
+    assert_eq!(
+        (count, bytes),
+        (0, 0),
+        "numbers format without heap traffic"
+    );
+    assert_eq!(numbers[0], "18446744073709551615");
+    assert_eq!(numbers[1], "-9223372036854775808");
+    assert_eq!(numbers[2], "512");
+    assert_eq!(numbers[3], "-0.125");
+    assert_eq!(numbers[4], "true");
+
+    let (count, bytes, formatted) = measure(|| {
+        let topic = black_box("orders");
+        (
+            cheetah_string::cheetah_format!("{topic}-{}", black_box(7)),
+            cheetah_string::cheetah_concat!(topic, "@", black_box("group-a")),
+        )
+    });
+    assert_eq!((count, bytes), (0, 0), "short macro output stays inline");
+    assert_eq!(formatted.0, "orders-7");
+    assert_eq!(formatted.1, "orders@group-a");
+
+    let topic = black_box("orders-with-a-long-name");
+    let (count, _, formatted) =
+        measure(|| cheetah_string::cheetah_format!("{topic}-{}", black_box(7)));
+    assert_eq!(
+        count, 2,
+        "long formatted output spills once, then freezes into one backing"
+    );
+    assert_eq!(formatted, "orders-with-a-long-name-7");
+    assert!(formatted.heap_bytes() < 64, "the backing is right-sized");
+
+    let (count, bytes, concatenated) =
+        measure(|| cheetah_string::cheetah_concat!(topic, "@", black_box("group-a")));
+    assert_eq!(count, 1, "long concatenations allocate one backing");
+    assert!(bytes < 64, "the backing is right-sized");
+    assert_eq!(concatenated, "orders-with-a-long-name@group-a");
+
+    let short_parts = [
+        CheetahString::from("orders"),
+        CheetahString::from("group-a"),
+    ];
+    let long_parts = [borrowed_value.clone(), owned_value.clone(), window.clone()];
+    let (count, bytes, joined) = measure(|| {
+        use cheetah_string::JoinCheetah;
+
+        (
+            CheetahString::join("@", black_box(&short_parts)),
+            CheetahString::concat(black_box(&short_parts)),
+            black_box(&short_parts[..1]).join_cheetah("@"),
+            black_box(&long_parts[..1]).join_cheetah("@"),
+        )
+    });
+    assert_eq!(
+        (count, bytes),
+        (0, 0),
+        "short and single-part joins never allocate"
+    );
+    assert_eq!(joined.0, "orders@group-a");
+    assert_eq!(joined.1, "ordersgroup-a");
+    assert_eq!(joined.2, "orders");
+    assert_eq!(
+        joined.3.as_bytes().as_ptr(),
+        borrowed_value.as_bytes().as_ptr()
+    );
+
+    let (count, bytes, joined) = measure(|| CheetahString::join(", ", black_box(&long_parts)));
+    assert_eq!(count, 1, "long joins allocate the shared backing once");
+    assert!(bytes <= joined.len() + 64, "the backing is right-sized");
+    assert_eq!(joined.len(), 1024 + 1024 + 40 + 4);
+
+    cheetah_string::known_strings! {
+        static KNOWN_KEYS = ["KEYS", "TAGS", "serializeTypeCurrentRPC"];
+    }
+    let decoded = String::from("serializeTypeCurrentRPC");
+    let (count, bytes, known) =
+        measure(|| CheetahString::from_known_or_slice(&KNOWN_KEYS, black_box(&decoded)));
+    assert_eq!(
+        (count, bytes),
+        (0, 0),
+        "known long keys resolve to static text"
+    );
+    assert_ne!(known.as_ptr(), decoded.as_ptr());
+
+    let (count, _, spilled_builder) = measure(|| {
+        let mut builder = CheetahBuilder::new();
+        builder.push_str(black_box("0123456789abcdef"));
+        builder.push_str(black_box("0123456789abcdef"));
+        builder.finish()
+    });
+    assert_eq!(count, 2, "spilling allocates a buffer, finish one backing");
+    assert_eq!(spilled_builder.len(), 32);
+
+    let (count, _, detached) = measure(|| black_box(&window).detach());
+    assert_eq!(count, 1, "detach copies into a right-sized backing");
+    assert_ne!(detached.as_bytes().as_ptr(), window.as_bytes().as_ptr());
+    assert_eq!(detached, window);
+
     #[cfg(feature = "bytes")]
     {
         use cheetah_string::CheetahBytes;
@@ -138,5 +412,29 @@ fn v3_allocation_and_clone_contracts() {
             measure(|| bytes::Bytes::from(black_box(cheetah_bytes)));
         assert_eq!((count, allocated), (0, 0));
         assert_eq!(roundtrip.as_ptr(), payload_pointer);
+
+        let frame = bytes::Bytes::from(vec![b'f'; 1024]);
+        let frame_pointer = frame.as_ptr();
+        let (count, _, shared_text) =
+            measure(|| CheetahString::try_from_shared_bytes(black_box(frame)).unwrap());
+        assert_eq!(count, 1, "sharing Bytes allocates only its handle");
+        assert_eq!(shared_text.as_bytes().as_ptr(), frame_pointer);
+        assert_clone_allocations(&shared_text);
     }
 }
+
+#[cfg(feature = "bytes")]
+#[test]
+fn windows_of_adopted_bytes_convert_back_without_allocating() {
+    let frame = bytes::Bytes::from("frame-".repeat(64).into_bytes());
+    // Cloning promotes the frame to shared storage up front, so later
+    // handles only bump its reference count.
+    let frame_handle = frame.clone();
+    let text = CheetahString::try_from_shared_bytes(frame).unwrap();
+    let window = text.substring(6, 120);
+
+    let (count, bytes, encoded) = measure(|| black_box(&window).clone().into_bytes());
+    assert_eq!((count, bytes), (0, 0), "windows slice the adopted buffer");
+    assert_eq!(encoded.as_ptr(), frame_handle[6..].as_ptr());
+    assert_eq!(encoded, frame_handle.slice(6..120));
+}
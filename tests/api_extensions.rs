@@ -275,6 +275,55 @@ fn test_add_long_strings() {
     );
 }
 
+#[test]
+fn test_add_assign() {
+    let mut s = CheetahString::from("order");
+    s += "@group-a";
+    assert_eq!(s, "order@group-a");
+
+    s += &CheetahString::from("-0123456789");
+    assert_eq!(s, "order@group-a-0123456789");
+    assert_eq!(s.len(), 24);
+
+    s += "";
+    assert_eq!(s, "order@group-a-0123456789");
+}
+
+#[test]
+fn test_transforms_across_inline_boundary() {
+    let s = CheetahString::from("abcdefghij");
+    assert_eq!(s.repeat(2), "abcdefghijabcdefghij");
+    assert_eq!(s.repeat(3), "abcdefghij".repeat(3));
+    assert_eq!(s.replace("j", "JJJJJJJ"), "abcdefghiJJJJJJJ");
+    assert_eq!(
+        s.replace("a", "0123456789abcdef"),
+        "0123456789abcdefbcdefghij"
+    );
+    assert_eq!(s.replacen("", "-", 3), "-a-b-cdefghij");
+
+    // Case mappings can change the byte length.
+    let german = CheetahString::from("stra\u{00DF}e");
+    assert_eq!(german.to_uppercase(), "STRASSE");
+    let sharp = CheetahString::from("\u{00DF}".repeat(11));
+    assert_eq!(sharp.to_uppercase(), "SS".repeat(11));
+    assert_eq!(sharp.to_uppercase().len(), 22);
+    let sharp = CheetahString::from("\u{00DF}".repeat(11) + "a");
+    assert_eq!(sharp.to_uppercase(), "SS".repeat(11) + "A");
+}
+
+#[test]
+fn test_lowercase_final_sigma_matches_std() {
+    for text in [
+        "\u{3A3}",
+        "O\u{3A3}",
+        "\u{3A3}A \u{3A3}O\u{3A3}",
+        "A\u{3A3}B",
+    ] {
+        let s = CheetahString::from(text);
+        assert_eq!(s.to_lowercase(), text.to_lowercase(), "{text:?}");
+    }
+}
+
 // Edge cases and unicode tests
 
 #[test]
@@ -324,3 +373,143 @@ fn test_whitespace_operations() {
     assert_eq!(s.len(), 6);
     assert_eq!(s.trim().len(), 0);
 }
+
+#[test]
+fn test_ascii_case_transforms() {
+    let s = CheetahString::from("Content-Type: ÉTÉ");
+
+    assert_eq!(s.to_ascii_uppercase(), "CONTENT-TYPE: ÉTÉ");
+    assert_eq!(s.to_ascii_lowercase(), "content-type: ÉtÉ");
+    assert_eq!(s.to_ascii_uppercase(), s.as_str().to_ascii_uppercase());
+
+    let mut inline = CheetahString::from("get");
+    inline.make_ascii_uppercase();
+    assert_eq!(inline, "GET");
+    inline.make_ascii_lowercase();
+    assert_eq!(inline, "get");
+
+    let long = CheetahString::from("x-forwarded-for-".repeat(4));
+    let mut shared = long.clone();
+    shared.make_ascii_uppercase();
+    assert_eq!(shared, long.as_str().to_ascii_uppercase());
+    assert_eq!(
+        long,
+        "x-forwarded-for-".repeat(4),
+        "shared backing is untouched"
+    );
+    let mut unchanged = long.clone();
+    unchanged.make_ascii_lowercase();
+    assert!(unchanged.ptr_eq(&long));
+}
+
+#[test]
+fn test_transforms_report_changes() {
+    use std::borrow::Cow;
+
+    let upper = CheetahString::from("ORDERS-ΣΊΣΥΦΟΣ-PRIMARY");
+    let lower = CheetahString::from("orders-σίσυφος-primary");
+
+    assert!(matches!(upper.to_uppercase_cow(), Cow::Borrowed(_)));
+    assert!(matches!(lower.to_lowercase_cow(), Cow::Borrowed(_)));
+    assert!(matches!(upper.to_ascii_uppercase_cow(), Cow::Borrowed(_)));
+    assert!(matches!(lower.to_ascii_lowercase_cow(), Cow::Borrowed(_)));
+    assert_eq!(
+        upper.to_lowercase_cow(),
+        Cow::<CheetahString>::Owned(lower.clone())
+    );
+    assert_eq!(lower.to_uppercase_cow().as_ref(), &upper);
+
+    assert!(matches!(
+        lower.replace_cow("missing", "x"),
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(lower.replace_cow("-", "-"), Cow::Borrowed(_)));
+    assert!(matches!(lower.replacen_cow("-", "+", 0), Cow::Borrowed(_)));
+    assert_eq!(
+        lower.replacen_cow("-", "+", 1).as_str(),
+        "orders+σίσυφος-primary"
+    );
+    assert_eq!(lower.replace_cow("", "").as_str(), lower.as_str());
+    assert_eq!(lower.replace("", "|"), lower.as_str().replace("", "|"));
+
+    // Characters whose case mappings expand are not no-ops.
+    let sharp = CheetahString::from("straße");
+    assert!(matches!(sharp.to_uppercase_cow(), Cow::Owned(_)));
+    assert_eq!(sharp.to_uppercase(), "STRASSE");
+    let title = CheetahString::from("ǅ");
+    assert_eq!(title.to_uppercase(), "Ǆ");
+    assert_eq!(title.to_lowercase(), "ǆ");
+}
+
+#[test]
+fn test_strip_and_trim_matches_match_std() {
+    let s = CheetahString::from("xxabcxx");
+
+    assert_eq!(s.strip_prefix("xx"), "xxabcxx".strip_prefix("xx"));
+    assert_eq!(s.strip_suffix('x'), "xxabcxx".strip_suffix('x'));
+    assert_eq!(s.strip_prefix("abc"), None);
+    assert_eq!(s.trim_matches('x'), "xxabcxx".trim_matches('x'));
+    assert_eq!(s.trim_start_matches("x"), "xxabcxx".trim_start_matches("x"));
+    assert_eq!(
+        s.trim_end_matches(|c| c == 'x'),
+        "xxabcxx".trim_end_matches('x')
+    );
+    assert_eq!(s.trim_matches(['x', 'a', 'c']), "b");
+    assert_eq!(s.trim_matches("xx"), "abc");
+    assert_eq!(s.trim_matches(""), "xxabcxx");
+    assert_eq!(CheetahString::from("xxxx").trim_matches('x'), "");
+    assert_eq!(CheetahString::from("ééaé").trim_start_matches('é'), "aé");
+}
+
+#[test]
+fn test_owned_trimming_shares_storage() {
+    let line = CheetahString::from(format!("\t{}  ", "record-".repeat(8)));
+    let trimmed = line.trimmed();
+    assert_eq!(trimmed, line.trim());
+    assert!(trimmed.ptr_eq(&line));
+    assert_eq!(line.trimmed_start(), line.trim_start());
+    assert_eq!(line.trimmed_end(), line.trim_end());
+    assert!(CheetahString::from(" \n ").trimmed().is_empty());
+
+    let unchanged = CheetahString::from("record-".repeat(8));
+    assert!(unchanged.trimmed().ptr_eq(&unchanged));
+    assert!(unchanged.trim_matches_owned(' ').ptr_eq(&unchanged));
+
+    let retry = CheetahString::from_static_str("%RETRY%consumer-group-orders-primary");
+    let original = retry.strip_prefix_owned("%RETRY%").unwrap();
+    assert_eq!(original, "consumer-group-orders-primary");
+    assert!(original.is_static());
+    assert_eq!(
+        original.as_static_str().unwrap().as_ptr(),
+        retry.as_bytes()[7..].as_ptr()
+    );
+
+    let shared = CheetahString::from(format!("{}.bak", "segment-".repeat(4)));
+    let stripped = shared.strip_suffix_owned(".bak").unwrap();
+    assert_eq!(stripped, "segment-".repeat(4));
+    assert!(stripped.ptr_eq(&shared));
+    assert_eq!(shared.strip_suffix_owned(".tmp"), None);
+
+    let short_static = CheetahString::from_static_str("  orders\n");
+    let trimmed_static = short_static.trimmed();
+    assert_eq!(trimmed_static, "orders");
+    assert!(trimmed_static.is_static());
+    assert_eq!(trimmed_static.as_static_str(), Some("orders"));
+    assert!(CheetahString::from_static_str("%RETRY%orders")
+        .strip_prefix_owned("%RETRY%")
+        .unwrap()
+        .is_static());
+
+    let short = CheetahString::from("%RETRY%orders");
+    let inline = short.strip_prefix_owned("%RETRY%").unwrap();
+    assert_eq!(inline, "orders");
+    assert!(inline.is_inline());
+
+    let padded = CheetahString::from("0000123");
+    assert_eq!(padded.trim_start_matches_owned('0'), "123");
+    assert_eq!(padded.trim_end_matches_owned('3'), "000012");
+    assert_eq!(
+        CheetahString::from("\"quoted\"").trim_matches_owned('"'),
+        "quoted"
+    );
+}
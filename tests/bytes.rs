@@ -95,3 +95,116 @@ fn serde_uses_bytes_semantics_for_cheetah_bytes() {
     let decoded: CheetahBytes = serde_json::from_str(&json).unwrap();
     assert_eq!(decoded.as_bytes(), &[0, 1, 255]);
 }
+
+#[test]
+fn shared_bytes_conversion_keeps_payload_without_copying() {
+    let frame = bytes::Bytes::from(format!("topic={}", "orders-".repeat(16)));
+    let value_bytes = frame.slice(6..);
+    let payload_pointer = value_bytes.as_ptr();
+
+    let value = CheetahString::try_from_shared_bytes(value_bytes).unwrap();
+    assert_eq!(value, "orders-".repeat(16));
+    assert_eq!(value.as_bytes().as_ptr(), payload_pointer);
+
+    let cloned = value.clone();
+    assert_eq!(cloned.as_bytes().as_ptr(), payload_pointer);
+
+    let window = value.substring(7, 91);
+    assert_eq!(window, "orders-".repeat(12));
+    assert_eq!(window.as_bytes().as_ptr(), payload_pointer.wrapping_add(7));
+
+    let converted = CheetahString::try_from(frame.slice(6..)).unwrap();
+    assert_eq!(converted.as_bytes().as_ptr(), payload_pointer);
+}
+
+#[test]
+fn cheetah_bytes_conversions_share_payload() {
+    let cheetah_bytes = CheetahBytes::from(bytes::Bytes::from(vec![b'z'; 1024]));
+    let source_pointer = cheetah_bytes.as_bytes().as_ptr();
+
+    let borrowed = cheetah_bytes.try_to_cheetah_string().unwrap();
+    assert_eq!(borrowed.as_bytes().as_ptr(), source_pointer);
+
+    let owned = cheetah_bytes.try_into_cheetah_string().unwrap();
+    assert_eq!(owned.as_bytes().as_ptr(), source_pointer);
+    assert_eq!(owned, borrowed);
+
+    let invalid = CheetahBytes::from_vec(vec![b'a', 0xFF]);
+    let error = invalid.try_into_cheetah_string().unwrap_err();
+    assert_eq!(error.utf8_error().valid_up_to(), 1);
+    assert_eq!(error.into_bytes().as_ref(), &[b'a', 0xFF]);
+}
+
+#[test]
+fn short_shared_bytes_are_stored_inline() {
+    let frame = bytes::Bytes::from(b"KEYS=orders".to_vec());
+    let value = CheetahString::try_from_shared_bytes(frame.slice(5..)).unwrap();
+
+    assert_eq!(value, "orders");
+    assert_ne!(value.as_bytes().as_ptr(), frame[5..].as_ptr());
+}
+
+#[test]
+fn detach_releases_adopted_frames() {
+    let frame = bytes::Bytes::from(vec![b'f'; 4096]);
+    let view = CheetahString::try_from_shared_bytes(frame.slice(100..140)).unwrap();
+    assert_eq!(view.as_ptr(), frame[100..].as_ptr());
+
+    let detached = view.detach();
+    let compacted = view.clone().compact();
+    assert_ne!(detached.as_ptr(), view.as_ptr());
+    assert_ne!(compacted.as_ptr(), view.as_ptr());
+    assert_eq!(detached, "f".repeat(40));
+
+    drop(view);
+    assert!(
+        frame.is_unique(),
+        "detached copies do not keep the frame alive"
+    );
+    assert_eq!(compacted, detached);
+}
+
+#[test]
+fn cheetah_string_into_bytes_reuses_backing() {
+    let static_value = CheetahString::from_static_str("a static topic name beyond inline size");
+    let static_bytes = static_value.to_bytes();
+    assert_eq!(static_bytes.as_ptr(), static_value.as_bytes().as_ptr());
+
+    let shared = CheetahString::from("header-value-".repeat(16));
+    let shared_pointer = shared.as_bytes().as_ptr();
+    let encoded = shared.to_bytes();
+    assert_eq!(encoded.as_ptr(), shared_pointer);
+    assert_eq!(encoded.as_ref(), shared.as_bytes());
+    drop(shared);
+    assert_eq!(encoded.len(), 13 * 16);
+
+    let parent = CheetahString::from("p".repeat(512));
+    let window = parent.substring(64, 256);
+    let window_bytes = bytes::Bytes::from(window.clone());
+    assert_eq!(window_bytes.as_ptr(), window.as_bytes().as_ptr());
+    assert_eq!(window_bytes.len(), 192);
+
+    let frame = bytes::Bytes::from(vec![b'f'; 256]);
+    let frame_pointer = frame.as_ptr();
+    let text = CheetahString::try_from_shared_bytes(frame).unwrap();
+    let cheetah_bytes = CheetahBytes::from(text);
+    assert_eq!(cheetah_bytes.as_bytes().as_ptr(), frame_pointer);
+
+    let inline = CheetahString::from("short");
+    assert_eq!(inline.into_bytes().as_ref(), b"short");
+}
+
+#[test]
+fn builder_finishes_into_bytes_without_copying() {
+    let mut builder = cheetah_string::CheetahBuilder::with_capacity(64);
+    builder.push_str("orders@group-a");
+    let pointer = builder.as_str().as_ptr();
+
+    let bytes = builder.clone().finish_bytes();
+    assert_eq!(bytes.as_ref(), b"orders@group-a");
+
+    let mut bytes_mut = builder.finish_bytes_mut();
+    assert_eq!(bytes_mut.as_ptr(), pointer);
+    bytes_mut.extend_from_slice(b"\r\n");
+    assert_eq!(&bytes_mut[..], b"orders@group-a\r\n");
+}
@@ -0,0 +1,138 @@
+use cheetah_string::{
+    AsciiFolding, CaseFolding, CaseInsensitive, CaseInsensitiveStr, CheetahString, UnicodeFolding,
+};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+type Unicode = CaseInsensitive<CheetahString, UnicodeFolding>;
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn ascii_folding_ignores_ascii_case_only() {
+    let header = CaseInsensitive::<CheetahString>::from("Content-Type");
+
+    assert_eq!(header, "content-type");
+    assert_eq!(header, *"CONTENT-TYPE");
+    assert_ne!(header, "content_type");
+    assert_ne!(CaseInsensitive::new("É"), "é");
+    assert_eq!(
+        hash_of(&header),
+        hash_of(&CaseInsensitive::<CheetahString>::from("CONTENT-type"))
+    );
+}
+
+#[test]
+fn unicode_folding_matches_simple_case_folding() {
+    let pairs = [
+        ("ÉCOLE", "école"),
+        ("ΣΊΣΥΦΟΣ", "σίσυφος"),
+        ("STRAẞE", "straße"),
+        ("\u{212A}elvin", "kelvin"),
+        ("ǅ", "ǆ"),
+        ("Ꭰ", "ꭰ"),
+    ];
+    for (upper, lower) in pairs {
+        let a = Unicode::from(upper);
+        let b = Unicode::from(lower);
+        assert_eq!(a, b, "{upper} vs {lower}");
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    assert_ne!(Unicode::from("straße"), "strasse");
+    assert_ne!(Unicode::from("ı"), "i");
+    assert_ne!(Unicode::from("İ"), "i");
+}
+
+#[test]
+fn ordering_compares_folded_text() {
+    let mut names: Vec<CaseInsensitive<&str>> = ["beta", "Alpha", "GAMMA", "alpha2"]
+        .into_iter()
+        .map(CaseInsensitive::new)
+        .collect();
+    names.sort();
+
+    let sorted: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+    assert_eq!(sorted, ["Alpha", "alpha2", "beta", "GAMMA"]);
+    assert_eq!(AsciiFolding::fold_cmp("a", "B"), Ordering::Less);
+    assert_eq!(UnicodeFolding::fold_cmp("ä", "Ä"), Ordering::Equal);
+}
+
+#[test]
+fn borrowed_lookups_use_plain_text() {
+    let mut headers = HashMap::new();
+    headers.insert(
+        CaseInsensitive::new(CheetahString::from("Content-Length")),
+        42,
+    );
+    headers.insert(CaseInsensitive::new(CheetahString::from("X-Request-Id")), 7);
+
+    assert_eq!(
+        headers.get(CaseInsensitiveStr::new("content-length")),
+        Some(&42)
+    );
+    assert_eq!(
+        headers.get(CaseInsensitiveStr::new("X-REQUEST-ID")),
+        Some(&7)
+    );
+    assert_eq!(headers.get(CaseInsensitiveStr::new("x-missing")), None);
+
+    let mut sorted = BTreeMap::new();
+    sorted.insert(Unicode::from("Ωmega"), 1);
+    assert_eq!(sorted.get(CaseInsensitiveStr::unicode("ωMEGA")), Some(&1));
+}
+
+#[test]
+fn borrowed_and_owned_forms_hash_alike_for_long_text() {
+    let long = "Header-".repeat(40) + "ΣΊΣΥΦΟΣ";
+    let owned = Unicode::from(long.as_str());
+    let borrowed = CaseInsensitiveStr::unicode(&long);
+
+    assert_eq!(hash_of(&owned), hash_of(borrowed));
+    assert_eq!(
+        hash_of(&owned),
+        hash_of(CaseInsensitiveStr::unicode(&long.to_lowercase()))
+    );
+}
+
+#[test]
+fn hashing_distinguishes_prefixes() {
+    let pairs = [("ab", "c"), ("a", "bc")];
+    let hashes: HashSet<u64> = pairs
+        .iter()
+        .map(|(a, b)| hash_of(&(CaseInsensitiveStr::new(a), CaseInsensitiveStr::new(b))))
+        .collect();
+    assert_eq!(hashes.len(), 2);
+}
+
+#[test]
+fn original_spelling_is_kept() {
+    let header = CaseInsensitive::<CheetahString>::from(String::from("ETag"));
+
+    assert_eq!(header.to_string(), "ETag");
+    assert_eq!(format!("{header:?}"), "\"ETag\"");
+    assert_eq!(header.get(), "ETag");
+    assert_eq!(header.len(), 4);
+    assert_eq!(header.into_inner(), "ETag");
+    assert_eq!(CaseInsensitiveStr::new("ETag").as_str(), "ETag");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trips_original_spelling() {
+    let header = CaseInsensitive::<CheetahString>::from("X-Trace-Id");
+
+    let json = serde_json::to_string(&header).unwrap();
+    let decoded: CaseInsensitive<CheetahString> = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(json, "\"X-Trace-Id\"");
+    assert_eq!(decoded.as_str(), "X-Trace-Id");
+    assert_eq!(decoded, "x-trace-id");
+}
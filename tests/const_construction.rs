@@ -0,0 +1,72 @@
+use cheetah_string::{cheetah, cheetah_const, CheetahString, CheetahString31};
+use std::mem::size_of_val;
+
+fn stored_inline<T>(value: &T, text: &str) -> bool {
+    let start = value as *const T as usize;
+    let pointer = text.as_ptr() as usize;
+    pointer >= start && pointer < start + size_of_val(value)
+}
+
+const TOPIC: &str = "orders";
+const GROUP: &str = "group-a";
+
+static STATIC_TOPIC: CheetahString = cheetah!(TOPIC);
+const ROUTE: CheetahString = cheetah_const!(TOPIC, "@", GROUP);
+const EMPTY: CheetahString = cheetah!("");
+const BOUNDARY: CheetahString = cheetah!("exactly-23-bytes-long!!");
+const UNICODE: CheetahString = cheetah_const!("β", "/", "火");
+
+static STATIC_ROUTE: CheetahString = ROUTE;
+static STATIC_EMPTY: CheetahString = EMPTY;
+
+const ROUTE_LEN: usize = STATIC_ROUTE.len();
+const EMPTY_IS_EMPTY: bool = STATIC_EMPTY.is_empty();
+const FIRST_BYTE: u8 = first_byte(&STATIC_ROUTE);
+const _: () = assert!(EMPTY_IS_EMPTY && !STATIC_ROUTE.is_empty());
+
+const fn first_byte(value: &CheetahString) -> u8 {
+    value.as_bytes()[0]
+}
+
+#[test]
+fn const_items_hold_inline_text() {
+    assert_eq!(STATIC_TOPIC, "orders");
+    assert_eq!(ROUTE, "orders@group-a");
+    assert_eq!(EMPTY, "");
+    assert_eq!(BOUNDARY.len(), 23);
+    assert_eq!(UNICODE, "β/火");
+
+    for value in [&STATIC_TOPIC, &ROUTE, &EMPTY, &BOUNDARY, &UNICODE] {
+        let value = value.clone();
+        assert!(stored_inline(&value, value.as_str()));
+        assert_eq!(value, CheetahString::from(value.as_str()));
+    }
+}
+
+#[test]
+fn accessors_evaluate_in_const_context() {
+    assert_eq!(ROUTE_LEN, 14);
+    assert_eq!(FIRST_BYTE, b'o');
+
+    static STATIC: CheetahString = CheetahString::from_static_str("a static str");
+    const STATIC_TEXT: &str = STATIC.as_str();
+    assert_eq!(STATIC_TEXT, "a static str");
+}
+
+#[test]
+fn inline_from_str_uses_the_target_capacity() {
+    const WIDE: CheetahString31 = CheetahString31::inline_from_str("a-key-of-twenty-eight-bytes!");
+    assert_eq!(WIDE.len(), 28);
+    let wide = WIDE;
+    assert!(stored_inline(&wide, wide.as_str()));
+
+    let runtime = CheetahString::inline_from_str("built at runtime");
+    assert_eq!(runtime, "built at runtime");
+}
+
+#[test]
+#[should_panic(expected = "inline capacity")]
+fn inline_from_str_panics_past_the_capacity_at_runtime() {
+    let text = String::from("a runtime string longer than the inline capacity");
+    let _ = CheetahString::inline_from_str(&text);
+}
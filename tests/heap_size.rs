@@ -0,0 +1,136 @@
+use cheetah_string::{CheetahBuilder, CheetahKey, CheetahString, HeapSize, MemoryTally};
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use std::mem::size_of;
+use std::sync::Arc;
+
+#[test]
+fn clones_and_slices_are_charged_once() {
+    let topic = CheetahString::from("topic-".repeat(20));
+    let block = topic.heap_bytes();
+    let values = [
+        topic.clone(),
+        topic.clone(),
+        topic.substring(6, 60),
+        CheetahString::from("inline"),
+        CheetahString::from_static_str("a static value that is long enough"),
+    ];
+
+    let mut tally = MemoryTally::new();
+    tally.add(&values);
+    assert_eq!(tally.unique_heap_bytes(), block);
+    assert_eq!(tally.shared_counted_bytes(), 3 * block);
+    assert_eq!(tally.inline_bytes(), "inline".len());
+    assert_eq!(tally.shared_allocations(), 1);
+
+    tally.add(&topic);
+    assert_eq!(tally.unique_heap_bytes(), block);
+    assert_eq!(tally.shared_counted_bytes(), 4 * block);
+
+    let copy = CheetahString::from(topic.as_str());
+    tally.add(&copy);
+    assert_eq!(tally.unique_heap_bytes(), 2 * block);
+    assert_eq!(tally.shared_allocations(), 2);
+}
+
+#[test]
+fn builders_keys_and_strings_report_owned_buffers() {
+    let mut builder = CheetahBuilder::new();
+    builder.push_str("short");
+    assert_eq!(builder.heap_size(), 0);
+    let mut tally = MemoryTally::new();
+    tally.add(&builder);
+    assert_eq!(tally.inline_bytes(), 5);
+
+    builder.reserve(200);
+    assert_eq!(builder.heap_size(), builder.capacity());
+
+    let key = CheetahKey::from("k".repeat(40));
+    assert_eq!(key.heap_size(), key.as_cheetah_string().heap_bytes());
+
+    let string = String::with_capacity(64);
+    assert_eq!(string.heap_size(), 64);
+    assert_eq!(7u64.heap_size(), 0);
+    assert_eq!("borrowed".heap_size(), 0);
+}
+
+#[test]
+fn collections_charge_their_buffers_and_contents() {
+    let long = CheetahString::from("value-".repeat(10));
+    let block = long.heap_bytes();
+
+    let mut list = Vec::with_capacity(8);
+    list.push(Some(long.clone()));
+    list.push(None);
+    assert_eq!(
+        list.heap_size(),
+        8 * size_of::<Option<CheetahString>>() + block
+    );
+
+    let ordered: BTreeMap<u32, CheetahString> = [(1, long.clone()), (2, long.clone())].into();
+    assert_eq!(
+        ordered.heap_size(),
+        2 * size_of::<(u32, CheetahString)>() + block
+    );
+
+    let boxed = Box::new(long.clone());
+    assert_eq!(boxed.heap_size(), size_of::<CheetahString>() + block);
+
+    let shared = Arc::new(vec![long.clone()]);
+    let mut tally = MemoryTally::new();
+    tally.add(&shared).add(&shared.clone()).add(&long);
+    let arc_block = 2 * size_of::<usize>() + size_of::<Vec<CheetahString>>();
+    let vec_buffer = shared.capacity() * size_of::<CheetahString>();
+    assert_eq!(tally.unique_heap_bytes(), arc_block + vec_buffer + block);
+    assert_eq!(
+        tally.shared_counted_bytes(),
+        2 * arc_block + vec_buffer + 2 * block
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_maps_charge_their_table_and_contents() {
+    let long = CheetahString::from("value-".repeat(10));
+    let block = long.heap_bytes();
+
+    let mut map = HashMap::new();
+    map.insert(CheetahString::from("a"), long.clone());
+    map.insert(CheetahString::from("b"), long.clone());
+    let buffer = map.capacity() * (size_of::<(CheetahString, CheetahString)>() + 1);
+    assert_eq!(map.heap_size(), buffer + block);
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn cheetah_bytes_dedupe_by_payload() {
+    use cheetah_string::CheetahBytes;
+
+    let payload = CheetahBytes::from_vec(vec![0; 128]);
+    let values = vec![payload.clone(), payload.clone(), CheetahBytes::new()];
+    let mut tally = MemoryTally::new();
+    tally.add(&values);
+    assert_eq!(
+        tally.unique_heap_bytes(),
+        values.capacity() * size_of::<CheetahBytes>() + 128
+    );
+    assert_eq!(tally.shared_allocations(), 1);
+}
+
+#[test]
+fn references_report_the_referenced_value() {
+    let values = vec![String::from("owned"), "x".repeat(64)];
+    let expected = values.heap_size();
+    assert!(expected > 0);
+
+    let mut tally = MemoryTally::new();
+    tally.add(&&values);
+    assert_eq!(tally.unique_heap_bytes(), expected);
+
+    let borrowed: Vec<&String> = values.iter().collect();
+    assert_eq!(
+        borrowed.heap_size(),
+        borrowed.capacity() * size_of::<&String>() + values[0].capacity() + values[1].capacity()
+    );
+}
@@ -0,0 +1,76 @@
+use cheetah_string::{CheetahString, CheetahString15, CheetahString31, CheetahString47};
+use std::collections::HashSet;
+use std::mem::size_of_val;
+
+fn stored_inline<T>(value: &T, text: &str) -> bool {
+    let start = value as *const T as usize;
+    let pointer = text.as_ptr() as usize;
+    pointer >= start && pointer < start + size_of_val(value)
+}
+
+#[test]
+fn capacity_selects_inline_boundary() {
+    let key = "7F00000100002A9F000000000001";
+    assert_eq!(key.len(), 28);
+
+    let narrow = CheetahString::from(key);
+    let wide = CheetahString31::from(key);
+    let widest = CheetahString47::from(key);
+    assert!(!stored_inline(&narrow, narrow.as_str()));
+    assert!(stored_inline(&wide, wide.as_str()));
+    assert!(stored_inline(&widest, widest.as_str()));
+
+    let small = CheetahString15::from("sixteen-bytes-xx");
+    assert!(!stored_inline(&small, small.as_str()));
+    let small = CheetahString15::from("fifteen-bytes-x");
+    assert!(stored_inline(&small, small.as_str()));
+}
+
+#[test]
+fn capacities_compare_hash_and_order_by_text() {
+    let narrow = CheetahString::from("broker-a");
+    let wide = CheetahString47::from("broker-a");
+    let later = CheetahString31::from("broker-b");
+
+    assert_eq!(narrow, wide);
+    assert_eq!(wide, narrow);
+    assert!(narrow < later);
+    assert!(later > wide);
+
+    let mut keys = HashSet::new();
+    keys.insert(wide.clone());
+    assert!(keys.contains("broker-a"));
+}
+
+#[test]
+fn into_capacity_shares_long_storage_and_inlines_short_text() {
+    let long = CheetahString::from("route-".repeat(16));
+    let wide: CheetahString47 = long.clone().into_capacity();
+    assert_eq!(wide, long);
+    assert_eq!(wide.as_ptr(), long.as_ptr());
+
+    let key = CheetahString::from("7F00000100002A9F000000000001");
+    let wide: CheetahString31 = key.clone().into_capacity();
+    assert_eq!(wide, key);
+    assert!(stored_inline(&wide, wide.as_str()));
+
+    let back: CheetahString = wide.into_capacity();
+    assert_eq!(back, key);
+
+    let static_value = CheetahString47::from_static_str("static");
+    let narrow: CheetahString = static_value.into_capacity();
+    assert_eq!(narrow.as_ptr(), "static".as_ptr());
+}
+
+#[test]
+fn wider_capacities_keep_the_full_api() {
+    let value = CheetahString47::from("topic-a topic-b topic-c");
+    let parts: Vec<CheetahString47> = value.split_owned(' ').collect();
+    assert_eq!(parts, ["topic-a", "topic-b", "topic-c"]);
+    assert_eq!(value.substring(0, 7), "topic-a");
+    assert!(value.contains(CheetahString::from("b t")));
+    assert_eq!(
+        String::from(value.clone() + "!"),
+        "topic-a topic-b topic-c!"
+    );
+}
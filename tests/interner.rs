@@ -0,0 +1,76 @@
+#![cfg(feature = "std")]
+
+use cheetah_string::{CheetahBuilder, CheetahInterner, CheetahString};
+use std::thread;
+
+const TOPIC: &str = "RMQ_SYS_TRACE_TOPIC_00042";
+
+#[test]
+fn equal_long_strings_share_one_allocation() {
+    let interner = CheetahInterner::new();
+    let first = interner.intern(TOPIC);
+    let second = interner.intern(&String::from(TOPIC));
+    let third = interner.intern_string(TOPIC.to_string());
+
+    assert_eq!(first, TOPIC);
+    assert_eq!(first.as_ptr(), second.as_ptr());
+    assert_eq!(first.as_ptr(), third.as_ptr());
+    assert_eq!(interner.len(), 1);
+    assert_eq!(interner.get(TOPIC).unwrap().as_ptr(), first.as_ptr());
+}
+
+#[test]
+fn inline_sized_strings_bypass_the_table() {
+    let interner = CheetahInterner::new();
+    let value = interner.intern("orders");
+
+    assert_eq!(value, "orders");
+    assert!(interner.is_empty());
+    assert_eq!(interner.get("orders"), None);
+}
+
+#[test]
+fn builder_finish_interned_returns_canonical_value() {
+    let interner = CheetahInterner::new();
+    let canonical = interner.intern("orders@consumer-group-alpha");
+
+    let mut builder = CheetahBuilder::new();
+    builder.push_str("orders");
+    builder.push('@');
+    builder.push_str("consumer-group-alpha");
+    let finished = builder.finish_interned(&interner);
+
+    assert_eq!(finished, canonical);
+    assert_eq!(finished.as_ptr(), canonical.as_ptr());
+}
+
+#[test]
+fn purge_reclaims_entries_without_outside_references() {
+    let interner = CheetahInterner::new();
+    let kept = interner.intern("consumer-group-kept-alive");
+    drop(interner.intern("consumer-group-released-now"));
+
+    assert_eq!(interner.len(), 2);
+    assert_eq!(interner.purge(), 1);
+    assert_eq!(interner.len(), 1);
+    assert_eq!(
+        interner.intern("consumer-group-kept-alive").as_ptr(),
+        kept.as_ptr()
+    );
+}
+
+#[test]
+fn global_interner_is_shared_across_threads() {
+    let text = "global-interner-topic-shared-across-threads";
+    let handles: Vec<_> = (0..4)
+        .map(|_| thread::spawn(move || CheetahInterner::global().intern(text)))
+        .collect();
+    let values: Vec<CheetahString> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    for value in &values {
+        assert_eq!(value.as_ptr(), values[0].as_ptr());
+    }
+
+    let scoped = CheetahInterner::new();
+    assert_ne!(scoped.intern(text).as_ptr(), values[0].as_ptr());
+}
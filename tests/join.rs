@@ -0,0 +1,85 @@
+use cheetah_string::{CheetahString, JoinCheetah};
+use std::cell::Cell;
+
+fn values(parts: &[&str]) -> Vec<CheetahString> {
+    parts.iter().copied().map(CheetahString::from).collect()
+}
+
+#[test]
+fn join_and_concat_match_std() {
+    for parts in [
+        &[][..],
+        &["orders"][..],
+        &["orders", "group-a"][..],
+        &["", "", ""][..],
+        &["topic", "a-much-longer-consumer-group-name", "βeta"][..],
+    ] {
+        let cheetah = values(parts);
+        assert_eq!(CheetahString::join("@", &cheetah), parts.join("@"));
+        assert_eq!(CheetahString::join("", cheetah.iter()), parts.concat());
+        assert_eq!(CheetahString::concat(&cheetah), parts.concat());
+        assert_eq!(cheetah.join_cheetah(", "), parts.join(", "));
+    }
+}
+
+#[test]
+fn single_part_is_cloned_not_copied() {
+    let long = CheetahString::from("shared-".repeat(16));
+    let joined = CheetahString::join("@", [&long]);
+    assert_eq!(joined.as_bytes().as_ptr(), long.as_bytes().as_ptr());
+
+    let concatenated = CheetahString::concat(std::slice::from_ref(&long));
+    assert_eq!(concatenated.as_bytes().as_ptr(), long.as_bytes().as_ptr());
+}
+
+#[test]
+fn join_tolerates_iterators_that_change_between_passes() {
+    #[derive(Clone)]
+    struct Shifting<'a> {
+        parts: &'a [CheetahString],
+        passes: &'a Cell<usize>,
+        index: usize,
+        started: bool,
+    }
+
+    impl<'a> Iterator for Shifting<'a> {
+        type Item = &'a CheetahString;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            if !self.started {
+                self.started = true;
+                self.passes.set(self.passes.get() + 1);
+                // Each pass starts one element later.
+                self.index = self.passes.get() - 1;
+            }
+            let part = self.parts.get(self.index)?;
+            self.index += 1;
+            Some(part)
+        }
+    }
+
+    let parts = values(&["zero-is-the-longest-piece", "one", "two", "three", "four"]);
+    let passes = Cell::new(0);
+    let iter = Shifting {
+        parts: &parts,
+        passes: &passes,
+        index: 0,
+        started: false,
+    };
+
+    let joined = CheetahString::join("-", iter);
+    assert!(joined.ends_with("four"));
+}
+
+#[test]
+fn from_iterator_collects_inline_sized_results() {
+    let collected: CheetahString = ["orders", "@", "group-a"].into_iter().collect();
+    assert_eq!(collected, "orders@group-a");
+
+    let collected: CheetahString = vec![String::from("a"); 40].into_iter().collect();
+    assert_eq!(collected, "a".repeat(40));
+
+    let chars = ['x', 'y', 'z'];
+    let collected: CheetahString = chars.iter().collect();
+    assert_eq!(collected, "xyz");
+}
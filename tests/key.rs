@@ -0,0 +1,111 @@
+use cheetah_string::{CheetahKey, CheetahKeyBuildHasher, CheetahKeyStr, CheetahString};
+use std::collections::hash_map::RandomState;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::BuildHasher;
+
+const PROPERTY_KEYS: &[&str] = &[
+    "KEYS",
+    "TAGS",
+    "WAIT",
+    "REAL_TOPIC",
+    "UNIQ_KEY",
+    "serializeTypeCurrentRPC",
+    "a-property-key-that-is-longer-than-the-inline-capacity",
+];
+
+#[test]
+fn prehashed_keys_work_as_hash_map_keys() {
+    let mut map = HashMap::with_hasher(CheetahKeyBuildHasher);
+    for (index, key) in PROPERTY_KEYS.iter().enumerate() {
+        map.insert(CheetahKey::from(*key), index);
+    }
+
+    for (index, key) in PROPERTY_KEYS.iter().enumerate() {
+        let lookup = CheetahKey::new(CheetahString::from_static_str(key));
+        assert_eq!(map.get(&lookup), Some(&index));
+    }
+    assert_eq!(map.get(&CheetahKey::from("MISSING")), None);
+}
+
+#[test]
+fn fingerprint_is_stable_across_representations() {
+    let long = "topic-".repeat(16);
+    let shared = CheetahKey::from(long.as_str());
+    let sliced = CheetahKey::new(CheetahString::from(format!("x{long}")).slice(1..));
+    let owned = CheetahKey::from(long.clone());
+
+    assert_eq!(shared.fingerprint(), sliced.fingerprint());
+    assert_eq!(shared.fingerprint(), owned.fingerprint());
+    assert_eq!(shared, sliced);
+    assert_eq!(shared, long.as_str());
+}
+
+#[test]
+fn fingerprints_distinguish_property_keys() {
+    let fingerprints: HashSet<u64> = PROPERTY_KEYS
+        .iter()
+        .map(|key| CheetahKey::from(*key).fingerprint())
+        .collect();
+
+    assert_eq!(fingerprints.len(), PROPERTY_KEYS.len());
+}
+
+#[test]
+fn build_hasher_passes_cached_value_through() {
+    let key = CheetahKey::from_static_str("REAL_TOPIC");
+
+    assert_eq!(CheetahKeyBuildHasher.hash_one(&key), key.fingerprint());
+    assert_eq!(
+        CheetahKeyBuildHasher.hash_one((&key, &key)),
+        CheetahKeyBuildHasher.hash_one((&key, &key))
+    );
+    assert_ne!(
+        CheetahKeyBuildHasher.hash_one((&key, &key)),
+        key.fingerprint()
+    );
+}
+
+#[test]
+fn build_hasher_supports_other_key_types() {
+    let mut map = HashMap::with_hasher(CheetahKeyBuildHasher);
+    map.insert("plain-str", 1);
+    map.insert("other-str", 2);
+
+    assert_eq!(map.get("plain-str"), Some(&1));
+    assert_eq!(map.get("other-str"), Some(&2));
+}
+
+#[test]
+fn borrowed_keys_look_up_by_text() {
+    let mut prehashed = HashMap::with_hasher(CheetahKeyBuildHasher);
+    let mut std_hashed = HashMap::with_hasher(RandomState::new());
+    let mut sorted = BTreeMap::new();
+    for (index, key) in PROPERTY_KEYS.iter().enumerate() {
+        prehashed.insert(CheetahKey::from(*key), index);
+        std_hashed.insert(CheetahKey::from(*key), index);
+        sorted.insert(CheetahKey::from(*key), index);
+    }
+
+    for (index, key) in PROPERTY_KEYS.iter().enumerate() {
+        let text = CheetahString::from(*key);
+        let borrowed = CheetahKeyStr::new(&text);
+        assert_eq!(prehashed.get(borrowed), Some(&index));
+        assert_eq!(std_hashed.get(borrowed), Some(&index));
+        assert_eq!(sorted.get(borrowed), Some(&index));
+        assert_eq!(borrowed.fingerprint(), CheetahKey::from(*key).fingerprint());
+    }
+    assert_eq!(prehashed.get(CheetahKeyStr::new("MISSING")), None);
+}
+
+#[test]
+fn borrowed_and_owned_keys_hash_alike() {
+    let state = RandomState::new();
+    for key in PROPERTY_KEYS {
+        let owned = CheetahKey::from(*key);
+        assert_eq!(state.hash_one(&owned), state.hash_one(owned.as_key_str()));
+        assert_eq!(
+            CheetahKeyBuildHasher.hash_one(&owned),
+            CheetahKeyBuildHasher.hash_one(CheetahKeyStr::new(key))
+        );
+    }
+}
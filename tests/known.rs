@@ -0,0 +1,83 @@
+use cheetah_string::{known_strings, CheetahString, CheetahString15, KnownStrings};
+
+const UNIQ_KEY: &str = "UNIQ_KEY";
+
+known_strings! {
+    /// Message property keys, as in the MQ property benches.
+    static PROPERTY_KEYS = [
+        "KEYS", "TAGS", "WAIT", "DELAY", "RETRY_TOPIC", "REAL_TOPIC", "REAL_QID",
+        "TRAN_MSG", "PGROUP", "MIN_OFFSET", "MAX_OFFSET", "BUYER_ID", "TRACE_ON",
+        "INSTANCE_ID", "CORRELATION_ID", "REPLY_TO_CLIENT", "TTL", UNIQ_KEY,
+        "BORN_HOST", "STORE_HOST", "serializeTypeCurrentRPC",
+        "MSG_REGION", "CLUSTER", "ORIGIN_MESSAGE_ID", "CONSUME_START_TIME",
+        "__transactionId__", "TRANSACTION_CHECK_TIMES", "CHECK_IMMUNITY_TIME_IN_SECONDS",
+    ];
+    static EMPTY = [];
+    static SINGLE = [""];
+}
+
+#[test]
+fn every_declared_word_resolves_to_itself() {
+    assert_eq!(PROPERTY_KEYS.len(), 28);
+    for (index, &word) in PROPERTY_KEYS.as_slice().iter().enumerate() {
+        assert_eq!(PROPERTY_KEYS.index(word), Some(index));
+        let found = PROPERTY_KEYS.get(&String::from(word)).unwrap();
+        assert_eq!(found.as_ptr(), word.as_ptr());
+        assert!(PROPERTY_KEYS.contains(word));
+    }
+}
+
+#[test]
+fn unknown_words_miss() {
+    for miss in [
+        "",
+        "keys",
+        "KEY",
+        "KEYS ",
+        "TAGS\0",
+        "UNIQ_KEY_",
+        "serializeTypeCurrentRPc",
+        "火",
+    ] {
+        assert_eq!(PROPERTY_KEYS.get(miss), None, "{miss:?}");
+    }
+    for i in 0..1000 {
+        assert!(!PROPERTY_KEYS.contains(&format!("KEY-{i}")));
+    }
+
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.get(""), None);
+    assert_eq!(SINGLE.get(""), Some(""));
+    assert_eq!(SINGLE.get("x"), None);
+}
+
+#[test]
+fn from_known_or_slice_returns_pointer_stable_values() {
+    let frames = [String::from("REAL_TOPIC"), String::from("REAL_TOPIC")];
+    let a = CheetahString::from_known_or_slice(&PROPERTY_KEYS, &frames[0]);
+    let b = CheetahString::from_known_or_slice(&PROPERTY_KEYS, &frames[1]);
+    assert_eq!(a, b);
+    assert_eq!(a.as_ptr(), b.as_ptr());
+    assert_eq!(
+        a.as_ptr(),
+        PROPERTY_KEYS.get("REAL_TOPIC").unwrap().as_ptr()
+    );
+
+    let narrow = CheetahString15::from_known_or_slice(&PROPERTY_KEYS, "serializeTypeCurrentRPC");
+    let declared = PROPERTY_KEYS.get("serializeTypeCurrentRPC").unwrap();
+    assert_eq!(narrow.as_ptr(), declared.as_ptr());
+    assert_eq!(narrow, "serializeTypeCurrentRPC");
+
+    let unknown = CheetahString::from_known_or_slice(&PROPERTY_KEYS, "custom-property");
+    assert_eq!(unknown, "custom-property");
+}
+
+#[test]
+fn registries_are_plain_statics() {
+    fn lookup(registry: &'static KnownStrings, value: &str) -> Option<&'static str> {
+        registry.get(value)
+    }
+
+    assert_eq!(lookup(&PROPERTY_KEYS, "TTL"), Some("TTL"));
+    assert_eq!(format!("{SINGLE:?}"), "{\"\"}");
+}
@@ -1,4 +1,6 @@
-use cheetah_string::CheetahString;
+use cheetah_string::{
+    CheetahString, CheetahString15, CheetahString31, CheetahString47, CheetahStringN,
+};
 use std::env;
 use std::fs;
 use std::mem::{align_of, size_of};
@@ -24,6 +26,9 @@ fn layout_snapshot() {
     let layouts = [
         layout_entry::<CheetahString>("CheetahString"),
         layout_entry::<Option<CheetahString>>("Option<CheetahString>"),
+        layout_entry::<CheetahString15>("CheetahString15"),
+        layout_entry::<CheetahString31>("CheetahString31"),
+        layout_entry::<CheetahString47>("CheetahString47"),
         layout_entry::<String>("String"),
         layout_entry::<Option<String>>("Option<String>"),
         layout_entry::<&str>("&str"),
@@ -58,18 +63,28 @@ fn layout_snapshot() {
 
     #[cfg(target_pointer_width = "64")]
     {
-        assert_eq!(size_of::<CheetahString>(), 32);
-        assert_eq!(size_of::<Option<CheetahString>>(), 32);
+        assert_eq!(size_of::<CheetahString>(), 24);
+        assert_eq!(size_of::<Option<CheetahString>>(), 24);
         assert_eq!(align_of::<CheetahString>(), 8);
+        // Static and sliced values need 16 bytes plus a tag, so smaller
+        // capacities do not shrink the value below 24 bytes.
+        assert_eq!(size_of::<CheetahStringN<0>>(), 24);
+        assert_eq!(size_of::<CheetahStringN<7>>(), 24);
+        assert_eq!(size_of::<CheetahString15>(), 24);
+        assert_eq!(size_of::<Option<CheetahString15>>(), 24);
+        assert_eq!(size_of::<CheetahString31>(), 32);
+        assert_eq!(size_of::<Option<CheetahString31>>(), 32);
+        assert_eq!(size_of::<CheetahString47>(), 48);
+        assert_eq!(size_of::<Option<CheetahString47>>(), 48);
     }
 
     #[cfg(target_pointer_width = "32")]
     {
-        // Measured on i686-pc-windows-msvc. InlineStr alone occupies 24 bytes,
-        // so the enum discriminant and 4-byte alignment produce a 28-byte
-        // representation on 32-bit targets.
-        assert_eq!(size_of::<CheetahString>(), 28);
-        assert_eq!(size_of::<Option<CheetahString>>(), 28);
+        // InlineStr occupies 24 bytes with its length byte last; the other
+        // variants fit in the first 12 bytes and reuse that byte's niche.
+        assert_eq!(size_of::<CheetahString>(), 24);
+        assert_eq!(size_of::<Option<CheetahString>>(), 24);
         assert_eq!(align_of::<CheetahString>(), 4);
+        assert_eq!(size_of::<CheetahString15>(), 16);
     }
 }
@@ -0,0 +1,82 @@
+use cheetah_string::{cheetah_concat, cheetah_format, CheetahString};
+use std::cell::Cell;
+use std::fmt;
+
+#[test]
+fn cheetah_format_matches_format() {
+    let topic = CheetahString::from("orders");
+    let queue_id = 7u32;
+
+    assert_eq!(cheetah_format!("{topic}-{queue_id}"), "orders-7");
+    assert_eq!(cheetah_format!("literal only"), "literal only");
+    assert_eq!(cheetah_format!(""), "");
+    assert_eq!(cheetah_format!("{:>5}|{:<4}|", 42, "x"), "   42|x   |");
+
+    let long = cheetah_format!("{topic}@{}-{queue_id:08}", "consumer-group");
+    assert_eq!(long, format!("{topic}@consumer-group-{queue_id:08}"));
+    assert!(long.len() > 23);
+}
+
+#[test]
+fn cheetah_format_tolerates_nondeterministic_display() {
+    struct Growing(Cell<usize>);
+
+    impl fmt::Display for Growing {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let len = self.0.get();
+            self.0.set(len + 7);
+            f.write_str(&"x".repeat(len))
+        }
+    }
+
+    let value = Growing(Cell::new(30));
+    let formatted = cheetah_format!("{value}");
+    assert_eq!(formatted, "x".repeat(30));
+    assert_eq!(value.0.get(), 37, "the Display impl runs once");
+}
+
+#[test]
+#[should_panic(expected = "formatting trait implementation returned an error")]
+fn cheetah_format_panics_on_display_errors_like_format() {
+    struct Failing;
+
+    impl fmt::Display for Failing {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    let _ = cheetah_format!("{Failing}");
+}
+
+#[test]
+fn cheetah_concat_accepts_string_like_parts() {
+    let topic = CheetahString::from("orders");
+    let group = String::from("group-a");
+
+    assert_eq!(cheetah_concat!(topic, "@", group), "orders@group-a");
+    assert_eq!(cheetah_concat!(), "");
+    assert_eq!(cheetah_concat!("only",), "only");
+
+    let long = cheetah_concat!(topic, "@", group, "/", "retry-".repeat(4));
+    assert_eq!(long, "orders@group-a/retry-retry-retry-retry-");
+}
+
+#[test]
+fn cheetah_format_runs_each_display_impl_once() {
+    struct Counted<'a>(&'a Cell<usize>, &'a str);
+
+    impl fmt::Display for Counted<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.set(self.0.get() + 1);
+            f.write_str(self.1)
+        }
+    }
+
+    for text in ["short", "a formatted value longer than the inline capacity"] {
+        let calls = Cell::new(0);
+        let value = cheetah_format!("[{}]", Counted(&calls, text));
+        assert_eq!(value, format!("[{text}]"));
+        assert_eq!(calls.get(), 1, "{text}");
+    }
+}
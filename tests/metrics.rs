@@ -0,0 +1,111 @@
+#![cfg(feature = "metrics")]
+
+use cheetah_string::metrics::{self, Constructor, MetricsSnapshot};
+use cheetah_string::{cheetah_concat, cheetah_format, CheetahBuilder, CheetahString, StorageKind};
+use std::sync::{Mutex, MutexGuard};
+
+// The counters are process-wide, so tests that read them run one at a time.
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn measure(f: impl FnOnce()) -> MetricsSnapshot {
+    let before = metrics::snapshot();
+    f();
+    metrics::snapshot().since(&before)
+}
+
+#[test]
+fn counts_storage_kinds_per_constructor() {
+    let _guard = lock();
+    let delta = measure(|| {
+        let _ = CheetahString::from_slice("inline");
+        let _ = CheetahString::from_string("shared-".repeat(8));
+        let _ = CheetahString::try_from_vec(b"vec".to_vec()).unwrap();
+        let _ = CheetahString::try_from_bytes(b"bytes").unwrap();
+        let _ = CheetahString::from_u32(5);
+    });
+
+    assert_eq!(delta.constructed(Constructor::FromSlice), 1);
+    assert_eq!(delta.constructed(Constructor::FromString), 1);
+    assert_eq!(delta.constructed(Constructor::FromVec), 1);
+    assert_eq!(delta.constructed(Constructor::FromBytes), 1);
+    assert_eq!(delta.constructed(Constructor::Number), 1);
+    assert_eq!(delta.created(StorageKind::Inline), 3);
+    assert_eq!(delta.created(StorageKind::Shared), 1);
+    assert_eq!(delta.created(StorageKind::Static), 1);
+    assert_eq!(delta.created(StorageKind::Sliced), 0);
+}
+
+#[test]
+fn counts_shared_allocations_by_block_size() {
+    let _guard = lock();
+    let mut value = None;
+    let delta = measure(|| value = Some(CheetahString::from_slice(&"x".repeat(100))));
+
+    let value = value.unwrap();
+    assert_eq!(delta.shared_allocations(), 1);
+    assert_eq!(delta.shared_bytes_allocated(), value.heap_bytes() as u64);
+}
+
+#[test]
+fn derived_values_are_not_counted_as_created() {
+    let _guard = lock();
+    let parent = CheetahString::from("parent-".repeat(8));
+    let delta = measure(|| {
+        let _ = parent.clone();
+        let _ = parent.substring(0, 30);
+        let _ = CheetahString::from_static_str("static");
+    });
+
+    assert_eq!(delta, MetricsSnapshot::default());
+
+    let delta = measure(|| {
+        let _ = CheetahString::join(",", [&parent]);
+        let _ = CheetahString::concat(std::slice::from_ref(&parent));
+    });
+    assert_eq!(delta, MetricsSnapshot::default());
+
+    let delta = measure(|| {
+        let _ = parent.substring(0, 30).detach();
+    });
+    assert_eq!(delta.created(StorageKind::Shared), 0);
+    assert_eq!(delta.shared_allocations(), 1);
+}
+
+#[test]
+fn counts_composite_constructors_once() {
+    let _guard = lock();
+    let parts = [CheetahString::from("a"), CheetahString::from("b")];
+    let queue_id = 7;
+    let delta = measure(|| {
+        let mut builder = CheetahBuilder::new();
+        builder.push_str("built");
+        let _ = builder.finish();
+        let _ = cheetah_format!("queue-{queue_id}");
+        let _ = cheetah_concat!("a", "b");
+        let _ = CheetahString::join(",", &parts);
+        let _ = CheetahString::concat(&parts);
+    });
+
+    assert_eq!(delta.constructed(Constructor::Builder), 1);
+    assert_eq!(delta.constructed(Constructor::Format), 1);
+    assert_eq!(delta.constructed(Constructor::Concat), 3);
+    assert_eq!(delta.constructed(Constructor::FromSlice), 0);
+    assert_eq!(delta.constructed(Constructor::FromString), 0);
+    assert_eq!(delta.created(StorageKind::Inline), 5);
+}
+
+#[test]
+fn reset_zeroes_every_counter() {
+    let _guard = lock();
+    let _ = CheetahString::from_string("reset-".repeat(8));
+    metrics::reset();
+
+    assert_eq!(metrics::snapshot(), MetricsSnapshot::default());
+    for constructor in Constructor::ALL {
+        assert_eq!(metrics::snapshot().constructed(constructor), 0);
+    }
+}
@@ -24,6 +24,35 @@ fn builder_mutations_match_string() {
     assert_eq!(builder.as_str(), string);
 }
 
+#[test]
+fn builder_spills_only_past_the_inline_limit() {
+    let mut builder = CheetahBuilder::with_capacity(8);
+    assert_eq!(builder.capacity(), 23);
+
+    builder.push_str("0123456789");
+    builder.reserve(13);
+    assert_eq!(builder.capacity(), 23);
+    builder.push_str("abcdefghijklm");
+    assert_eq!(builder.len(), 23);
+    assert_eq!(builder.capacity(), 23);
+
+    builder.push('火');
+    assert_eq!(builder.as_str(), "0123456789abcdefghijklm火");
+    assert!(builder.capacity() >= 26);
+
+    builder.clear();
+    let spilled_capacity = builder.capacity();
+    builder.push_str("short");
+    assert_eq!(builder.capacity(), spilled_capacity);
+    assert_eq!(builder.clone().finish(), "short");
+    assert_eq!(builder.into_string(), "short");
+
+    let mut builder = CheetahBuilder::new();
+    builder.push_str("inline");
+    assert_eq!(builder.into_string(), "inline");
+    assert_eq!(CheetahBuilder::default().finish(), "");
+}
+
 #[test]
 fn builder_finish_and_into_string_have_distinct_contracts() {
     let long = "canonical-".repeat(32);
@@ -78,3 +107,155 @@ fn concatenation_produces_a_new_immutable_value() {
     assert_eq!(original, "hello");
     assert_eq!(combined, "hello world");
 }
+
+#[test]
+fn builder_edits_match_string() {
+    for seed in [
+        "topic@group",
+        "a-much-longer-builder-value::with::segments",
+        "eta/β/火/ω",
+    ] {
+        let mut builder = CheetahBuilder::from(seed);
+        let mut string = String::from(seed);
+
+        builder.insert_str(0, "ns:");
+        string.insert_str(0, "ns:");
+        builder.insert(3, '/');
+        string.insert(3, '/');
+        assert_eq!(builder.as_str(), string);
+
+        assert_eq!(builder.pop(), string.pop());
+        assert_eq!(builder.remove(0), string.remove(0));
+        assert_eq!(builder.as_str(), string);
+
+        let drained = builder.drain(1..4);
+        let expected: String = string.drain(1..4).collect();
+        assert_eq!(drained, expected);
+        assert_eq!(builder.as_str(), string);
+
+        builder.replace_range(..2, "[replaced-prefix]");
+        string.replace_range(..2, "[replaced-prefix]");
+        assert_eq!(builder.as_str(), string);
+
+        builder.extend_from_within(1..9);
+        let copied = string[1..9].to_owned();
+        string.push_str(&copied);
+        assert_eq!(builder.as_str(), string);
+
+        builder.retain(|ch| ch != '-' && ch != 'e');
+        string.retain(|ch| ch != '-' && ch != 'e');
+        assert_eq!(builder.as_str(), string);
+
+        let tail = builder.split_off(5);
+        let expected_tail = string.split_off(5);
+        assert_eq!(tail.as_str(), expected_tail);
+        assert_eq!(builder.as_str(), string);
+
+        builder.truncate(2);
+        string.truncate(2);
+        builder.truncate(64);
+        assert_eq!(builder.finish(), string);
+    }
+
+    let mut builder = CheetahBuilder::new();
+    assert_eq!(builder.pop(), None);
+    builder.push_str("ωω");
+    builder.extend_from_within(..);
+    assert_eq!(builder.as_str(), "ωωωω");
+}
+
+#[test]
+fn builder_edits_report_invalid_offsets() {
+    use cheetah_string::Error;
+
+    let mut builder = CheetahBuilder::from("héllo");
+
+    assert_eq!(
+        builder.try_truncate(2),
+        Err(Error::InvalidCharBoundary { index: 2 })
+    );
+    assert_eq!(
+        builder.try_insert(9, 'x'),
+        Err(Error::IndexOutOfBounds { index: 9, len: 6 })
+    );
+    assert_eq!(
+        builder.try_insert_str(2, "x"),
+        Err(Error::InvalidCharBoundary { index: 2 })
+    );
+    assert_eq!(
+        builder.try_remove(6),
+        Err(Error::IndexOutOfBounds { index: 6, len: 6 })
+    );
+    let (start, end) = (4, 2);
+    assert_eq!(
+        builder.try_drain(start..end).unwrap_err(),
+        Error::InvalidRange { start: 4, end: 2 }
+    );
+    assert_eq!(
+        builder.try_replace_range(..=1, "e"),
+        Err(Error::InvalidCharBoundary { index: 2 })
+    );
+    assert_eq!(
+        builder.try_split_off(7).unwrap_err(),
+        Error::IndexOutOfBounds { index: 7, len: 6 }
+    );
+    assert_eq!(
+        builder.try_extend_from_within(2..),
+        Err(Error::InvalidCharBoundary { index: 2 })
+    );
+    assert_eq!(builder.as_str(), "héllo");
+}
+
+#[test]
+#[should_panic(expected = "character boundary")]
+fn builder_insert_panics_inside_a_character() {
+    let mut builder = CheetahBuilder::from("héllo");
+    builder.insert(2, 'x');
+}
+
+#[test]
+fn builder_formats_numbers_and_display_values() {
+    use std::fmt::Write;
+
+    let (topic, queue_id) = (CheetahString::from("orders"), 7);
+    let mut builder = CheetahBuilder::new();
+    write!(builder, "{topic}-{queue_id}").unwrap();
+    builder.push(':');
+    builder.push_u64(u64::MAX);
+    builder.push(':');
+    builder.push_i64(i64::MIN);
+    builder.push(':');
+    builder.push_u32(0);
+    builder.push(':');
+    builder.push_usize(1024);
+    builder.push(':');
+    builder.push_hex(0xdead_beef);
+    assert_eq!(
+        builder.as_str(),
+        "orders-7:18446744073709551615:-9223372036854775808:0:1024:deadbeef"
+    );
+
+    for value in [
+        0.1,
+        -2.5,
+        1.0,
+        1e21,
+        5e-324,
+        f64::MAX,
+        f64::NAN,
+        f64::INFINITY,
+    ] {
+        let mut builder = CheetahBuilder::new();
+        builder.push_f64(value);
+        assert_eq!(builder.as_str(), value.to_string());
+        if value.is_finite() {
+            assert_eq!(builder.as_str().parse::<f64>().unwrap(), value);
+        }
+    }
+
+    let mut builder = CheetahBuilder::new();
+    builder.push_display(&CheetahString::from("queue"));
+    builder.push_display(&'#');
+    builder.push_display("3");
+    assert_eq!(builder.finish(), "queue#3");
+}
@@ -0,0 +1,125 @@
+use cheetah_string::{CheetahString, CheetahString15, Error};
+
+macro_rules! assert_integer_conversions {
+    ($($ty:ty),*) => {
+        $(
+            for value in [<$ty>::MIN, <$ty>::MAX, 0, 1, 9, 10, 100, 127] {
+                let expected = value.to_string();
+                assert_eq!(CheetahString::from(value), expected);
+                assert_eq!(CheetahString15::from(value), expected);
+                let parsed: $ty = CheetahString::from(value).to_string().parse().unwrap();
+                assert_eq!(parsed, value);
+            }
+        )*
+    };
+}
+
+#[test]
+fn integer_conversions_match_to_string() {
+    assert_integer_conversions!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+    assert_eq!(CheetahString::from_u64(1024), "1024");
+    assert_eq!(CheetahString::from_u64(1025), "1025");
+    assert_eq!(CheetahString::from_i32(-1), "-1");
+    assert_eq!(CheetahString::from_usize(7), "7");
+}
+
+#[test]
+fn bool_and_float_conversions_match_to_string() {
+    assert_eq!(CheetahString::from(true), "true");
+    assert_eq!(CheetahString::from_bool(false), "false");
+
+    for value in [0.0, -0.0, 0.1, 1.5e-7, 123456.789, 1e21, 5e-324, f64::MAX] {
+        let converted = CheetahString::from(value);
+        assert_eq!(converted, value.to_string());
+        assert_eq!(converted.parse_f64(), Ok(value));
+    }
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert_eq!(CheetahString::from_f64(value), value.to_string());
+    }
+    for value in [0.1f32, f32::MIN_POSITIVE, f32::MAX, -3.25] {
+        let converted = CheetahString::from(value);
+        assert_eq!(converted, value.to_string());
+        assert_eq!(converted.parse_f32(), Ok(value));
+    }
+}
+
+#[test]
+fn parse_helpers_accept_std_syntax() {
+    assert_eq!(CheetahString::from("+42").parse_u8(), Ok(42));
+    assert_eq!(CheetahString::from("255").parse_u8(), Ok(255));
+    assert_eq!(CheetahString::from("-128").parse_i8(), Ok(-128));
+    assert_eq!(
+        CheetahString::from("18446744073709551615").parse_u64(),
+        Ok(u64::MAX)
+    );
+    assert_eq!(
+        CheetahString::from("-170141183460469231731687303715884105728").parse_i128(),
+        Ok(i128::MIN)
+    );
+    assert_eq!(CheetahString::from("007").parse_usize(), Ok(7));
+    assert_eq!(CheetahString::from("-0").parse_isize(), Ok(0));
+    assert_eq!(CheetahString::from("1e3").parse_f64(), Ok(1000.0));
+    assert_eq!(
+        CheetahString::from("-inf").parse_f64(),
+        Ok(f64::NEG_INFINITY)
+    );
+    assert!(CheetahString::from("NaN").parse_f32().unwrap().is_nan());
+    assert_eq!(CheetahString::from("true").parse_bool(), Ok(true));
+    assert_eq!(CheetahString::from("false").parse_bool(), Ok(false));
+}
+
+#[test]
+fn parse_helpers_report_the_failing_byte() {
+    let cases: [(&str, Error); 8] = [
+        ("", Error::InvalidNumber { index: 0 }),
+        ("+", Error::InvalidNumber { index: 1 }),
+        ("12a4", Error::InvalidNumber { index: 2 }),
+        ("-5", Error::InvalidNumber { index: 0 }),
+        (" 1", Error::InvalidNumber { index: 0 }),
+        ("4294967296", Error::NumberOverflow { index: 9 }),
+        ("99999999999", Error::NumberOverflow { index: 9 }),
+        ("42949672950", Error::NumberOverflow { index: 10 }),
+    ];
+    for (text, error) in cases {
+        assert_eq!(
+            CheetahString::from(text).parse_u32(),
+            Err(error),
+            "{text:?}"
+        );
+        assert!(text.parse::<u32>().is_err());
+    }
+
+    assert_eq!(
+        CheetahString::from("-129").parse_i8(),
+        Err(Error::NumberOverflow { index: 3 })
+    );
+    assert_eq!(
+        CheetahString::from("--1").parse_i64(),
+        Err(Error::InvalidNumber { index: 1 })
+    );
+    assert_eq!(
+        CheetahString::from("1.5.2").parse_f64(),
+        Err(Error::InvalidNumber { index: 3 })
+    );
+    assert_eq!(
+        CheetahString::from("1e+").parse_f32(),
+        Err(Error::InvalidNumber { index: 3 })
+    );
+    assert_eq!(
+        CheetahString::from("trUe").parse_bool(),
+        Err(Error::InvalidBool { index: 2 })
+    );
+    assert_eq!(
+        CheetahString::from("truex").parse_bool(),
+        Err(Error::InvalidBool { index: 4 })
+    );
+    assert_eq!(
+        Error::InvalidBool { index: 0 }.to_string(),
+        "invalid bool at byte 0"
+    );
+    assert_eq!(
+        Error::NumberOverflow { index: 9 }.to_string(),
+        "number overflows at byte 9"
+    );
+}
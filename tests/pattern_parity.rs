@@ -0,0 +1,280 @@
+// These parity checks cover the deprecated `split` entry point as well.
+#![allow(deprecated)]
+
+use cheetah_string::CheetahString;
+use std::borrow::Cow;
+
+const INPUTS: &[&str] = &[
+    "",
+    "a",
+    "aa",
+    "aaa",
+    "aaaa",
+    "a,b;c",
+    ",a,,b;",
+    "  leading and trailing  ",
+    "火,水;風",
+    "é",
+    "ééé",
+    "a::b::::c::",
+    "line1\nline2\r\n\nline3\n",
+    "abcXXXabcYYYabc",
+];
+
+macro_rules! assert_forward_parity {
+    ($input:expr, $pat:expr) => {{
+        let input: &str = $input;
+        let value = CheetahString::from(input);
+        let ctx = format!("input={:?}", input);
+
+        assert_eq!(value.find($pat), input.find($pat), "find {ctx}");
+        assert_eq!(value.rfind($pat), input.rfind($pat), "rfind {ctx}");
+        assert_eq!(value.contains($pat), input.contains($pat), "contains {ctx}");
+        assert_eq!(
+            value.starts_with($pat),
+            input.starts_with($pat),
+            "starts_with {ctx}"
+        );
+        assert_eq!(
+            value.ends_with($pat),
+            input.ends_with($pat),
+            "ends_with {ctx}"
+        );
+        assert_eq!(
+            value.split($pat).collect::<Vec<_>>(),
+            input.split($pat).collect::<Vec<_>>(),
+            "split {ctx}"
+        );
+        assert_eq!(
+            value.rsplit($pat).collect::<Vec<_>>(),
+            input.rsplit($pat).collect::<Vec<_>>(),
+            "rsplit {ctx}"
+        );
+        assert_eq!(
+            value.split_terminator($pat).collect::<Vec<_>>(),
+            input.split_terminator($pat).collect::<Vec<_>>(),
+            "split_terminator {ctx}"
+        );
+        assert_eq!(
+            value.rsplit_terminator($pat).collect::<Vec<_>>(),
+            input.rsplit_terminator($pat).collect::<Vec<_>>(),
+            "rsplit_terminator {ctx}"
+        );
+        assert_eq!(
+            value.split_inclusive($pat).collect::<Vec<_>>(),
+            input.split_inclusive($pat).collect::<Vec<_>>(),
+            "split_inclusive {ctx}"
+        );
+        for n in 0..5 {
+            assert_eq!(
+                value.splitn(n, $pat).collect::<Vec<_>>(),
+                input.splitn(n, $pat).collect::<Vec<_>>(),
+                "splitn({n}) {ctx}"
+            );
+            assert_eq!(
+                value.rsplitn(n, $pat).collect::<Vec<_>>(),
+                input.rsplitn(n, $pat).collect::<Vec<_>>(),
+                "rsplitn({n}) {ctx}"
+            );
+        }
+        assert_eq!(
+            value.split_once($pat),
+            input.split_once($pat),
+            "split_once {ctx}"
+        );
+        assert_eq!(
+            value.rsplit_once($pat),
+            input.rsplit_once($pat),
+            "rsplit_once {ctx}"
+        );
+        assert_eq!(
+            value.matches($pat).collect::<Vec<_>>(),
+            input.matches($pat).collect::<Vec<_>>(),
+            "matches {ctx}"
+        );
+        assert_eq!(
+            value.rmatches($pat).collect::<Vec<_>>(),
+            input.rmatches($pat).collect::<Vec<_>>(),
+            "rmatches {ctx}"
+        );
+        assert_eq!(
+            value.match_indices($pat).collect::<Vec<_>>(),
+            input.match_indices($pat).collect::<Vec<_>>(),
+            "match_indices {ctx}"
+        );
+        assert_eq!(
+            value.rmatch_indices($pat).collect::<Vec<_>>(),
+            input.rmatch_indices($pat).collect::<Vec<_>>(),
+            "rmatch_indices {ctx}"
+        );
+    }};
+}
+
+macro_rules! assert_reverse_parity {
+    ($input:expr, $pat:expr) => {{
+        let input: &str = $input;
+        let value = CheetahString::from(input);
+        let ctx = format!("input={:?}", input);
+
+        assert_eq!(
+            value.split($pat).rev().collect::<Vec<_>>(),
+            input.split($pat).rev().collect::<Vec<_>>(),
+            "split.rev {ctx}"
+        );
+        assert_eq!(
+            value.rsplit($pat).rev().collect::<Vec<_>>(),
+            input.rsplit($pat).rev().collect::<Vec<_>>(),
+            "rsplit.rev {ctx}"
+        );
+        assert_eq!(
+            value.split_terminator($pat).rev().collect::<Vec<_>>(),
+            input.split_terminator($pat).rev().collect::<Vec<_>>(),
+            "split_terminator.rev {ctx}"
+        );
+        assert_eq!(
+            value.split_inclusive($pat).rev().collect::<Vec<_>>(),
+            input.split_inclusive($pat).rev().collect::<Vec<_>>(),
+            "split_inclusive.rev {ctx}"
+        );
+        assert_eq!(
+            value.matches($pat).rev().collect::<Vec<_>>(),
+            input.matches($pat).rev().collect::<Vec<_>>(),
+            "matches.rev {ctx}"
+        );
+        assert_eq!(
+            value.match_indices($pat).rev().collect::<Vec<_>>(),
+            input.match_indices($pat).rev().collect::<Vec<_>>(),
+            "match_indices.rev {ctx}"
+        );
+
+        let mut ours = value.split($pat);
+        let mut theirs = input.split($pat);
+        for step in 0..8 {
+            if step % 2 == 0 {
+                assert_eq!(ours.next(), theirs.next(), "split.alternate {ctx}");
+            } else {
+                assert_eq!(
+                    ours.next_back(),
+                    theirs.next_back(),
+                    "split.alternate {ctx}"
+                );
+            }
+        }
+    }};
+}
+
+#[test]
+fn char_patterns_match_standard_library() {
+    for &input in INPUTS {
+        for ch in [',', 'a', '火', 'é', '\n', ' '] {
+            assert_forward_parity!(input, ch);
+            assert_reverse_parity!(input, ch);
+        }
+    }
+}
+
+#[test]
+fn str_patterns_match_standard_library() {
+    for &input in INPUTS {
+        for pat in ["", "a", "aa", "aaa", "::", "abc", "水;", "\r\n", "missing"] {
+            assert_forward_parity!(input, pat);
+            assert_forward_parity!(input, &pat);
+            let owned = String::from(pat);
+            assert_forward_parity!(input, &owned);
+        }
+    }
+}
+
+#[test]
+fn cheetah_string_patterns_match_str_patterns() {
+    for &input in INPUTS {
+        for pat in ["", "aa", "::", "火"] {
+            let value = CheetahString::from(input);
+            let needle = CheetahString::from(pat);
+
+            assert_eq!(value.find(&needle), input.find(pat));
+            assert_eq!(value.rfind(&needle), input.rfind(pat));
+            assert_eq!(
+                value.split(&needle).collect::<Vec<_>>(),
+                input.split(pat).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                value.rsplit(&needle).collect::<Vec<_>>(),
+                input.rsplit(pat).collect::<Vec<_>>()
+            );
+            assert_eq!(
+                value.match_indices(&needle).collect::<Vec<_>>(),
+                input.match_indices(pat).collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn char_set_patterns_match_standard_library() {
+    for &input in INPUTS {
+        let slice: &[char] = &[',', ';', '火'];
+        assert_forward_parity!(input, slice);
+        assert_reverse_parity!(input, slice);
+
+        assert_forward_parity!(input, [',', ';']);
+        assert_reverse_parity!(input, [',', ';']);
+
+        assert_forward_parity!(input, &['a', 'é', '\n']);
+        assert_reverse_parity!(input, &['a', 'é', '\n']);
+
+        let empty: [char; 0] = [];
+        assert_forward_parity!(input, empty);
+        assert_reverse_parity!(input, empty);
+    }
+}
+
+#[test]
+fn closure_patterns_match_standard_library() {
+    for &input in INPUTS {
+        assert_forward_parity!(input, char::is_whitespace);
+        assert_reverse_parity!(input, char::is_whitespace);
+
+        assert_forward_parity!(input, |c: char| !c.is_ascii());
+        assert_reverse_parity!(input, |c: char| !c.is_ascii());
+
+        assert_forward_parity!(input, |c: char| c == 'a' || c == ':');
+        assert_reverse_parity!(input, |c: char| c == 'a' || c == ':');
+    }
+}
+
+#[test]
+fn split_remainder_tracks_unyielded_text() {
+    let value = CheetahString::from("a,b;c");
+    let mut parts = value.split([',', ';']);
+
+    assert_eq!(parts.remainder(), Some("a,b;c"));
+    assert_eq!(parts.next(), Some("a"));
+    assert_eq!(parts.remainder(), Some("b;c"));
+    assert_eq!(parts.next_back(), Some("c"));
+    assert_eq!(parts.remainder(), Some("b"));
+    assert_eq!(parts.next(), Some("b"));
+    assert_eq!(parts.remainder(), None);
+    assert_eq!(parts.next(), None);
+}
+
+#[test]
+fn owned_string_needles_keep_compiling() {
+    let value = CheetahString::from("a::b::c");
+
+    assert_eq!(value.find(String::from("::")), Some(1));
+    assert_eq!(value.rfind(String::from("::")), Some(4));
+    assert_eq!(value.find(CheetahString::from("b")), Some(3));
+    assert_eq!(value.find(Box::<str>::from("c")), Some(6));
+    assert_eq!(value.rfind(Cow::Borrowed("::")), Some(4));
+    assert_eq!(value.find(Cow::<str>::Owned("x".into())), None);
+    assert!(value.contains(String::from("b::")));
+    assert_eq!(
+        value.rsplit(String::from("::")).collect::<Vec<_>>(),
+        ["c", "b", "a"]
+    );
+    assert_eq!(
+        value.split(CheetahString::from("::")).collect::<Vec<_>>(),
+        ["a", "b", "c"]
+    );
+}
@@ -48,3 +48,61 @@ fn reusable_empty_finder_matches_start() {
     assert_eq!(finder.find_in(&s), Some(0));
     assert!(finder.is_match(&s));
 }
+
+#[test]
+fn ascii_case_insensitive_queries_ignore_ascii_case_only() {
+    let s = CheetahString::from("Content-Type: Text/Plain; Charset=UTF-8");
+
+    assert!(s.eq_ignore_ascii_case("content-type: text/plain; charset=utf-8"));
+    assert!(s.starts_with_ignore_ascii_case("CONTENT-type"));
+    assert!(s.ends_with_ignore_ascii_case("utf-8"));
+    assert!(!s.starts_with_ignore_ascii_case("content-type: text/plain; charset=utf-8!"));
+    assert_eq!(s.find_ignore_ascii_case("text/PLAIN"), Some(14));
+    assert!(s.contains_ignore_ascii_case("CHARSET"));
+    assert!(!s.contains_ignore_ascii_case("charset=utf-16"));
+    assert_eq!(s.find_ignore_ascii_case(""), Some(0));
+
+    let accented = CheetahString::from("Évènement");
+    assert!(!accented.eq_ignore_ascii_case("évènement"));
+    assert_eq!(accented.find_ignore_ascii_case("NEMENT"), Some(5));
+    assert!(!accented.ends_with_ignore_ascii_case("ÈNEMENT"));
+}
+
+#[test]
+fn ascii_case_insensitive_search_matches_lowercased_search() {
+    let haystacks = ["aAaAb", "xyzXYZ", "abcABCabc", "ab", "", "éaÉA"];
+    let needles = ["", "a", "AB", "aab", "zx", "CAB", "éa", "b"];
+
+    for haystack in haystacks {
+        let value = CheetahString::from(haystack);
+        for needle in needles {
+            let expected = haystack
+                .to_ascii_lowercase()
+                .find(&needle.to_ascii_lowercase());
+            assert_eq!(
+                value.find_ignore_ascii_case(needle),
+                expected,
+                "{haystack:?} {needle:?}"
+            );
+            let finder = CheetahFinder::new_ignore_ascii_case(needle);
+            assert_eq!(finder.find_in(&value), expected, "{haystack:?} {needle:?}");
+            assert_eq!(finder.needle(), needle);
+        }
+    }
+}
+
+#[test]
+fn ascii_case_insensitive_ordering_folds_letters() {
+    use std::cmp::Ordering;
+
+    let s = CheetahString::from("Topic-B");
+
+    assert_eq!(s.cmp_ignore_ascii_case("topic-b"), Ordering::Equal);
+    assert_eq!(s.cmp_ignore_ascii_case("TOPIC-A"), Ordering::Greater);
+    assert_eq!(s.cmp_ignore_ascii_case("topic-b2"), Ordering::Less);
+    // `_` sorts between the two cases in byte order, but before letters here.
+    assert_eq!(
+        CheetahString::from("A").cmp_ignore_ascii_case("_"),
+        Ordering::Greater
+    );
+}
@@ -44,3 +44,26 @@ fn try_substring_reports_public_error_variants() {
         Err(Error::InvalidCharBoundary { index: 1 })
     ));
 }
+
+#[test]
+fn cheetah_key_roundtrips_as_plain_string() {
+    let key = cheetah_string::CheetahKey::from("REAL_TOPIC");
+
+    let json = serde_json::to_string(&key).unwrap();
+    let decoded: cheetah_string::CheetahKey = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(json, "\"REAL_TOPIC\"");
+    assert_eq!(decoded, key);
+    assert_eq!(decoded.fingerprint(), key.fingerprint());
+}
+
+#[test]
+fn wider_inline_capacities_roundtrip_as_plain_strings() {
+    let value = cheetah_string::CheetahString47::from("7F00000100002A9F000000000001");
+
+    let json = serde_json::to_string(&value).unwrap();
+    let decoded: cheetah_string::CheetahString47 = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(json, "\"7F00000100002A9F000000000001\"");
+    assert_eq!(decoded, value);
+}
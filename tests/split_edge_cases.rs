@@ -90,3 +90,90 @@ fn test_string_pattern_consecutive_separators() {
     let parts: Vec<&str> = s.split_str("::").collect();
     assert_eq!(parts, vec!["a", "b", "", "c", ""]);
 }
+
+#[test]
+fn split_at_and_slice_views_match_str() {
+    let text = "topic-".repeat(16) + "é-tail";
+    let value = CheetahString::from(text.as_str());
+
+    for mid in [0, 1, 23, 24, 50, text.len() - 5, text.len()] {
+        let (head, tail) = value.split_at(mid);
+        let (expected_head, expected_tail) = text.split_at(mid);
+        assert_eq!(head, expected_head);
+        assert_eq!(tail, expected_tail);
+        assert_eq!(value.slice(..mid), expected_head);
+        assert_eq!(value.slice(mid..), expected_tail);
+    }
+
+    let boundary = text.find('é').unwrap() + 1;
+    assert_eq!(
+        value.try_split_at(boundary),
+        Err(cheetah_string::Error::InvalidCharBoundary { index: boundary })
+    );
+    assert_eq!(
+        value.try_slice(..=text.len()),
+        Err(cheetah_string::Error::IndexOutOfBounds {
+            index: text.len() + 1,
+            len: text.len(),
+        })
+    );
+}
+
+#[test]
+fn owned_piece_iterators_match_borrowed_iterators() {
+    let long = format!(
+        "{}\r\n\n  {} \t{}\n",
+        "x".repeat(30),
+        "y".repeat(5),
+        "火".repeat(12)
+    );
+    let inputs = [
+        String::new(),
+        "a b  c".to_owned(),
+        "line1\nline2\r\n".to_owned(),
+        long,
+    ];
+
+    for input in &inputs {
+        for value in [
+            CheetahString::from(input.as_str()),
+            CheetahString::from_string(input.clone()),
+        ] {
+            let as_strs = |pieces: Vec<CheetahString>| {
+                pieces
+                    .iter()
+                    .map(|piece| piece.as_str().to_owned())
+                    .collect::<Vec<_>>()
+            };
+
+            assert_eq!(
+                as_strs(value.split_owned(' ').collect()),
+                input.split(' ').collect::<Vec<_>>()
+            );
+            assert_eq!(
+                as_strs(value.split_owned(' ').rev().collect()),
+                input.split(' ').rev().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                as_strs(value.split_owned("\r\n").collect()),
+                input.split("\r\n").collect::<Vec<_>>()
+            );
+            assert_eq!(
+                as_strs(value.lines_owned().collect()),
+                input.lines().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                as_strs(value.split_whitespace_owned().rev().collect()),
+                input.split_whitespace().rev().collect::<Vec<_>>()
+            );
+            assert_eq!(
+                value
+                    .split_once_owned('\n')
+                    .map(|(head, tail)| (head.as_str().to_owned(), tail.as_str().to_owned())),
+                input
+                    .split_once('\n')
+                    .map(|(head, tail)| (head.to_owned(), tail.to_owned()))
+            );
+        }
+    }
+}
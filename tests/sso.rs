@@ -1,4 +1,4 @@
-use cheetah_string::CheetahString;
+use cheetah_string::{CheetahString, StorageKind};
 
 #[test]
 fn test_sso_empty_string() {
@@ -33,6 +33,8 @@ fn test_sso_boundary_23_bytes() {
     let cs = CheetahString::from(s23.as_str());
     assert_eq!(cs.len(), 23);
     assert_eq!(cs.as_str(), s23);
+    assert!(cs.is_inline());
+    assert_eq!(cs.heap_bytes(), 0);
 }
 
 #[test]
@@ -42,6 +44,8 @@ fn test_sso_boundary_24_bytes() {
     let cs = CheetahString::from(s24.as_str());
     assert_eq!(cs.len(), 24);
     assert_eq!(cs.as_str(), s24);
+    assert_eq!(cs.storage_kind(), StorageKind::Shared);
+    assert_eq!(cs.shared_count(), Some(1));
 }
 
 #[test]
@@ -60,6 +64,8 @@ fn test_sso_clone_short_string() {
     assert_eq!(s1, s2);
     assert_eq!(s1.as_str(), "hello");
     assert_eq!(s2.as_str(), "hello");
+    assert!(s2.is_inline());
+    assert!(!s1.ptr_eq(&s2));
 }
 
 #[test]
@@ -209,3 +215,44 @@ fn test_sso_try_from_vec() {
     assert_eq!(s.as_str(), "hello");
     assert_eq!(s.len(), 5);
 }
+
+#[test]
+fn test_sso_option_uses_inline_length_niche() {
+    let values = [
+        Some(CheetahString::from("inline")),
+        Some(CheetahString::from_static_str("static")),
+        Some(CheetahString::from("shared".repeat(8))),
+        Some(CheetahString::from("shared".repeat(8)).substring(4, 40)),
+        None,
+    ];
+
+    assert_eq!(values[0].as_deref(), Some("inline"));
+    assert_eq!(values[1].as_deref(), Some("static"));
+    assert_eq!(values[2].as_ref().map(CheetahString::len), Some(48));
+    assert_eq!(values[3].as_ref().map(CheetahString::len), Some(36));
+    assert!(values[4].is_none());
+}
+
+#[test]
+fn test_sso_shared_clones_release_across_threads() {
+    let value = CheetahString::from("thread-shared-".repeat(8));
+    let window = value.substring(14, 56);
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let value = value.clone();
+            let window = window.clone();
+            std::thread::spawn(move || {
+                let copies: Vec<_> = (0..16).map(|_| value.clone()).collect();
+                assert!(copies.iter().all(|copy| copy == &value));
+                assert_eq!(window.as_str(), &value.as_str()[14..56]);
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    drop(value);
+    assert_eq!(window, "thread-shared-".repeat(8)[14..56]);
+}
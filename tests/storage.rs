@@ -0,0 +1,111 @@
+use cheetah_string::{CheetahString, CheetahString47, StorageKind};
+
+#[test]
+fn storage_kind_reports_each_representation() {
+    let long = "storage-".repeat(8);
+    let shared = CheetahString::from(long.as_str());
+    let sliced = shared.substring(0, 40);
+    let values = [
+        (CheetahString::from("inline"), StorageKind::Inline),
+        (
+            CheetahString::from_static_str("static"),
+            StorageKind::Static,
+        ),
+        (shared.clone(), StorageKind::Shared),
+        (sliced.clone(), StorageKind::Sliced),
+        (shared.substring(0, 8), StorageKind::Inline),
+    ];
+
+    for (value, kind) in &values {
+        assert_eq!(value.storage_kind(), *kind, "{value:#?}");
+        assert_eq!(value.is_inline(), *kind == StorageKind::Inline);
+        assert_eq!(value.is_static(), *kind == StorageKind::Static);
+    }
+
+    assert_eq!(values[1].0.as_static_str(), Some("static"));
+    assert_eq!(values[0].0.as_static_str(), None);
+    assert_eq!(shared.as_static_str(), None);
+}
+
+#[test]
+fn shared_count_and_heap_bytes_track_the_backing() {
+    let shared = CheetahString::from("x".repeat(1000));
+    assert_eq!(shared.shared_count(), Some(1));
+    let block = shared.heap_bytes();
+    assert!((1000..=1000 + 64).contains(&block), "{block}");
+
+    let clone = shared.clone();
+    let view = shared.substring(10, 500);
+    assert_eq!(shared.shared_count(), Some(3));
+    assert_eq!(view.shared_count(), Some(3));
+    assert_eq!(view.heap_bytes(), block);
+
+    let detached = view.detach();
+    assert_eq!(detached.shared_count(), Some(1));
+    assert!(detached.heap_bytes() < block);
+
+    drop((clone, view));
+    assert_eq!(shared.shared_count(), Some(1));
+
+    for value in [
+        CheetahString::from("inline"),
+        CheetahString::from_static_str("static"),
+    ] {
+        assert_eq!(value.shared_count(), None);
+        assert_eq!(value.heap_bytes(), 0);
+    }
+}
+
+#[test]
+fn ptr_eq_compares_storage_not_text() {
+    let shared = CheetahString::from("frame-".repeat(16));
+    let copy = CheetahString::from(shared.as_str());
+    assert_eq!(shared, copy);
+    assert!(!shared.ptr_eq(&copy));
+    assert!(shared.ptr_eq(&shared.clone()));
+    assert!(shared.ptr_eq(&shared.substring(6, 60)));
+
+    let wide: CheetahString47 = shared.clone().into_capacity();
+    assert!(wide.ptr_eq(&shared));
+
+    const TEXT: &str = "a static value";
+    let a = CheetahString::from_static_str(TEXT);
+    let b = CheetahString::from_static_str(TEXT);
+    assert!(a.ptr_eq(&b));
+    assert!(!a.ptr_eq(&CheetahString::from_static_str(&TEXT[1..])));
+
+    let inline = CheetahString::from("inline");
+    assert!(inline.ptr_eq(&inline));
+    assert!(!inline.ptr_eq(&inline.clone()));
+    assert!(!inline.ptr_eq(&a));
+}
+
+#[test]
+fn alternate_debug_shows_the_representation() {
+    let inline = CheetahString::from("queue");
+    assert_eq!(format!("{inline:?}"), "\"queue\"");
+    assert_eq!(
+        format!("{inline:#?}"),
+        "CheetahString {\n    storage: Inline,\n    len: 5,\n    value: \"queue\",\n}"
+    );
+
+    let shared = CheetahString::from("q".repeat(30));
+    let _clone = shared.clone();
+    let debug = format!("{shared:#?}");
+    assert!(debug.contains("storage: Shared"), "{debug}");
+    assert!(debug.contains("shared_count: 2"), "{debug}");
+    assert!(
+        debug.contains(&format!("heap_bytes: {}", shared.heap_bytes())),
+        "{debug}"
+    );
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn adopted_bytes_report_their_payload() {
+    let frame = bytes::Bytes::from(vec![b'b'; 256]);
+    let value = CheetahString::try_from_shared_bytes(frame).unwrap();
+    assert_eq!(value.storage_kind(), StorageKind::Shared);
+    assert!(value.heap_bytes() >= 256);
+    assert_eq!(value.shared_count(), Some(1));
+}